@@ -1,3 +1,7 @@
+use std::mem::MaybeUninit;
+
+use serde::{Deserialize, Serialize};
+
 /// `AmphoreusArena` is a deterministic bump allocator for simulation-frame data.
 ///
 /// The arena is reset in O(1) by moving `offset` back to zero.
@@ -5,30 +9,140 @@
 pub struct AmphoreusArena {
     pub memory: Vec<u8>,
     pub offset: usize,
+    peak_offset: usize,
+    granularity: usize,
+    allocations: u64,
+}
+
+/// Usage figures for sizing an arena; see `AmphoreusArena::stats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArenaStats {
+    pub capacity: usize,
+    /// Bytes in use now, rounding and alignment padding included.
+    pub current: usize,
+    /// See `AmphoreusArena::high_water_mark`.
+    pub high_water: usize,
+    /// Successful allocations since creation; black tides do not reset it.
+    pub allocations: u64,
+}
+
+/// An arena offset captured by `AmphoreusArena::savepoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaMarker {
+    offset: usize,
 }
 
 impl AmphoreusArena {
     /// Creates a new arena with a fixed contiguous capacity.
     pub fn new(capacity: usize) -> Self {
+        Self::with_granularity(capacity, 1)
+    }
+
+    /// Like `new`, but every allocation starts on and is rounded up to a `granularity` boundary.
+    ///
+    /// `granularity` is raised to the next power of two; 0 and 1 leave sizes unrounded.
+    pub fn with_granularity(capacity: usize, granularity: usize) -> Self {
         Self {
             memory: vec![0_u8; capacity],
             offset: 0,
+            peak_offset: 0,
+            granularity: granularity.max(1).next_power_of_two(),
+            allocations: 0,
         }
     }
 
+    pub fn granularity(&self) -> usize {
+        self.granularity
+    }
+
+    /// Changes the granularity for subsequent allocations; existing ones are left in place.
+    pub fn set_granularity(&mut self, granularity: usize) {
+        self.granularity = granularity.max(1).next_power_of_two();
+    }
+
+    /// Bytes an allocation of `len` consumes once rounded up to the granularity.
+    pub fn rounded_len(&self, len: usize) -> usize {
+        len.next_multiple_of(self.granularity)
+    }
+
+    /// Fraction of capacity in use, rounding included; an empty arena reports 0.
+    pub fn utilization(&self) -> f64 {
+        if self.memory.is_empty() {
+            return 0.0;
+        }
+        self.offset as f64 / self.memory.len() as f64
+    }
+
     /// O(1) world wipe: reset the allocation pointer.
     pub fn trigger_black_tide(&mut self) {
         self.offset = 0;
     }
 
+    /// Marks the current offset, so allocations made after it can be released together.
+    pub fn savepoint(&self) -> ArenaMarker {
+        ArenaMarker {
+            offset: self.offset,
+        }
+    }
+
+    /// Releases every allocation made since `marker`, which later allocations then reuse.
+    ///
+    /// Savepoints nest, but must be restored innermost first. A marker ahead of the current
+    /// offset, e.g. one taken before a black tide, is a bug: debug builds panic and release
+    /// builds leave the offset where it is.
+    pub fn restore(&mut self, marker: ArenaMarker) {
+        debug_assert!(
+            marker.offset <= self.offset,
+            "arena marker at {} is ahead of the current offset {}",
+            marker.offset,
+            self.offset
+        );
+        self.offset = self.offset.min(marker.offset);
+    }
+
+    /// Largest `offset` reached since creation or the last `reset_high_water_mark`.
+    ///
+    /// Survives black tides, so it reports the worst single cycle's usage.
+    pub fn high_water_mark(&self) -> usize {
+        self.peak_offset.max(self.offset)
+    }
+
+    pub fn reset_high_water_mark(&mut self) {
+        self.peak_offset = self.offset;
+    }
+
+    pub fn stats(&self) -> ArenaStats {
+        ArenaStats {
+            capacity: self.memory.len(),
+            current: self.offset,
+            high_water: self.high_water_mark(),
+            allocations: self.allocations,
+        }
+    }
+
     /// Returns the currently used byte region.
     pub fn used_bytes(&self) -> &[u8] {
         let used = self.offset.min(self.memory.len());
         &self.memory[..used]
     }
 
+    /// Whether `alloc_bytes(len, align)` would currently succeed.
+    pub fn can_alloc(&self, len: usize, align: usize) -> bool {
+        let align = align.max(1);
+        if !align.is_power_of_two() {
+            return false;
+        }
+
+        let align = align.max(self.granularity);
+        let aligned_offset = (self.offset + (align - 1)) & !(align - 1);
+        aligned_offset
+            .checked_add(self.rounded_len(len))
+            .is_some_and(|end| end <= self.memory.len())
+    }
+
     /// Deterministic aligned byte allocation from the bump arena.
     ///
+    /// The returned slice is `len` bytes long, while the arena advances by the rounded size.
     /// Returns `None` if there is not enough capacity or alignment is invalid.
     pub fn alloc_bytes(&mut self, len: usize, align: usize) -> Option<&mut [u8]> {
         let align = align.max(1);
@@ -36,13 +150,212 @@ impl AmphoreusArena {
             return None;
         }
 
+        let align = align.max(self.granularity);
         let aligned_offset = (self.offset + (align - 1)) & !(align - 1);
-        let end = aligned_offset.checked_add(len)?;
+        let end = aligned_offset.checked_add(self.rounded_len(len))?;
         if end > self.memory.len() {
             return None;
         }
 
         self.offset = end;
-        self.memory.get_mut(aligned_offset..end)
+        self.peak_offset = self.peak_offset.max(end);
+        self.allocations += 1;
+        self.memory.get_mut(aligned_offset..aligned_offset + len)
+    }
+
+    /// Uninitialized storage for one `T`; see `alloc_slice`.
+    pub fn alloc<T>(&mut self) -> Option<&mut MaybeUninit<T>> {
+        self.alloc_slice::<T>(1)?.first_mut()
+    }
+
+    /// Uninitialized storage for `len` values of `T`, sized and aligned from the type.
+    ///
+    /// Unlike `alloc_bytes`, which aligns offsets, this aligns the real address, since the
+    /// backing buffer only guarantees byte alignment; the start is rounded up to `T`'s
+    /// alignment or the granularity, whichever is larger. Offsets stay deterministic as
+    /// long as the buffer address is aligned at least that much, which heap buffers are for
+    /// alignments up to 16 on common allocators. Returns `None` if there is not enough
+    /// capacity.
+    pub fn alloc_slice<T>(&mut self, len: usize) -> Option<&mut [MaybeUninit<T>]> {
+        let size = size_of::<T>().checked_mul(len)?;
+        let align = align_of::<T>().max(self.granularity);
+        let base = self.memory.as_ptr() as usize;
+        let start = base
+            .checked_add(self.offset)?
+            .checked_next_multiple_of(align)?
+            - base;
+        let end = start.checked_add(self.rounded_len(size))?;
+        if end > self.memory.len() {
+            return None;
+        }
+
+        self.offset = end;
+        self.peak_offset = self.peak_offset.max(end);
+        self.allocations += 1;
+        let bytes = &mut self.memory[start..start + size];
+        // SAFETY: `bytes` is an exclusive borrow of `self.memory`, so the slice inherits the
+        // `&mut self` lifetime and nothing else can alias it. It spans exactly
+        // `size_of::<T>() * len` bytes, the size of `[MaybeUninit<T>; len]`, and `start` was
+        // rounded up so its address is a multiple of `align_of::<T>()`; zero-sized requests
+        // still get that aligned, non-null address. `MaybeUninit<T>` accepts any bit pattern,
+        // so reinterpreting initialized bytes as uninitialized `T`s is sound.
+        Some(unsafe { std::slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), len) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn high_water_mark_keeps_the_largest_cycle_across_black_tides() {
+        let mut arena = AmphoreusArena::new(1 << 12);
+        for cycle_bytes in [300, 1_200, 64, 800] {
+            for _ in 0..cycle_bytes / 4 {
+                assert!(arena.alloc_bytes(4, 4).is_some());
+            }
+            arena.trigger_black_tide();
+        }
+        assert_eq!(arena.offset, 0);
+        assert_eq!(arena.high_water_mark(), 1_200);
+
+        assert!(arena.alloc_bytes(100, 1).is_some());
+        arena.reset_high_water_mark();
+        assert_eq!(arena.high_water_mark(), 100);
+        arena.trigger_black_tide();
+        assert!(arena.alloc_bytes(40, 1).is_some());
+        assert_eq!(arena.high_water_mark(), 100);
+    }
+
+    #[test]
+    fn granular_allocations_start_on_boundaries_and_count_the_rounding() {
+        let mut arena = AmphoreusArena::with_granularity(256, 16);
+        let mut starts = Vec::new();
+        for len in [1, 5, 16, 17] {
+            let start = arena.memory.as_ptr() as usize;
+            let slice = arena.alloc_bytes(len, 1).expect("arena has room");
+            assert_eq!(slice.len(), len);
+            starts.push(slice.as_ptr() as usize - start);
+        }
+        assert_eq!(starts, vec![0, 16, 32, 48]);
+        assert_eq!(arena.offset, 80);
+        assert_eq!(arena.utilization(), 80.0 / 256.0);
+
+        let mut unrounded = AmphoreusArena::new(256);
+        for len in [1, 5, 16, 17] {
+            assert!(unrounded.alloc_bytes(len, 1).is_some());
+        }
+        assert_eq!(unrounded.offset, 39);
+        assert_eq!(AmphoreusArena::with_granularity(64, 12).granularity(), 16);
+    }
+
+    #[test]
+    fn restoring_a_savepoint_reuses_the_released_region() {
+        let mut arena = AmphoreusArena::new(256);
+        arena.alloc_bytes(16, 8).expect("arena has room");
+        let outer = arena.savepoint();
+        let scratch = arena.alloc_bytes(32, 8).expect("arena has room").as_ptr();
+        let inner = arena.savepoint();
+        arena.alloc_bytes(64, 8).expect("arena has room");
+
+        arena.restore(inner);
+        assert_eq!(arena.offset, 48);
+        arena.restore(outer);
+        assert_eq!(arena.offset, 16);
+        let reused = arena.alloc_bytes(32, 8).expect("arena has room").as_ptr();
+        assert_eq!(reused, scratch);
+        assert_eq!(arena.high_water_mark(), 112);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ahead of the current offset")]
+    fn restoring_a_marker_ahead_of_the_offset_panics_in_debug() {
+        let mut arena = AmphoreusArena::new(64);
+        arena.alloc_bytes(8, 1).expect("arena has room");
+        let marker = arena.savepoint();
+        arena.trigger_black_tide();
+        arena.restore(marker);
+    }
+
+    #[repr(align(16))]
+    struct Aligned16 {
+        _bytes: [u8; 24],
+    }
+
+    /// Allocates a `T` after an odd-sized byte allocation and checks its address.
+    fn check_aligned<T>() {
+        let mut arena = AmphoreusArena::new(256);
+        arena.alloc_bytes(3, 1).expect("arena has room");
+        let one = arena.alloc::<T>().expect("arena has room") as *mut MaybeUninit<T>;
+        assert!(one.is_aligned());
+        let many = arena.alloc_slice::<T>(5).expect("arena has room");
+        assert_eq!(many.len(), 5);
+        assert!(many.as_ptr().is_aligned());
+    }
+
+    #[test]
+    fn typed_allocations_are_aligned_for_their_type() {
+        assert_eq!(
+            [align_of::<u8>(), align_of::<u64>(), align_of::<Aligned16>()],
+            [1, 8, 16]
+        );
+        check_aligned::<u8>();
+        check_aligned::<u64>();
+        check_aligned::<Aligned16>();
+    }
+
+    #[test]
+    fn typed_allocations_round_trip_values() {
+        let mut arena = AmphoreusArena::new(64);
+        let slot = arena.alloc::<u64>().expect("arena has room");
+        assert_eq!(*slot.write(0xdead_beef), 0xdead_beef);
+    }
+
+    #[test]
+    fn exhausted_arena_refuses_typed_allocations_without_moving() {
+        let mut arena = AmphoreusArena::new(64);
+        arena.alloc_slice::<u64>(7).expect("56 bytes fit");
+        let before = (arena.offset, arena.high_water_mark());
+
+        assert!(arena.alloc_slice::<u64>(2).is_none());
+        assert!(arena.alloc::<Aligned16>().is_none());
+        assert!(arena.alloc_slice::<u64>(usize::MAX).is_none());
+        assert_eq!((arena.offset, arena.high_water_mark()), before);
+
+        assert!(arena.alloc::<u64>().is_some());
+        assert!(arena.alloc::<u8>().is_none());
+    }
+
+    #[test]
+    fn stats_track_a_peak_that_later_cycles_allocate_past() {
+        let mut arena = AmphoreusArena::new(1 << 10);
+        assert_eq!(
+            arena.stats(),
+            ArenaStats {
+                capacity: 1 << 10,
+                ..ArenaStats::default()
+            }
+        );
+
+        arena.alloc_bytes(200, 8).expect("arena has room");
+        arena.alloc::<u64>().expect("arena has room");
+        arena.trigger_black_tide();
+        arena.alloc_bytes(104, 8).expect("arena has room");
+        let quiet = arena.stats();
+        assert_eq!((quiet.current, quiet.high_water), (104, 208));
+
+        // Outgrowing the earlier cycle moves the peak; failed allocations count for nothing.
+        arena.alloc_bytes(300, 8).expect("arena has room");
+        assert!(arena.alloc_bytes(1 << 10, 1).is_none());
+        assert_eq!(
+            arena.stats(),
+            ArenaStats {
+                capacity: 1 << 10,
+                current: 404,
+                high_water: 404,
+                allocations: 4,
+            }
+        );
     }
 }
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 #[cfg(all(feature = "desktop", not(target_arch = "wasm32")))]
 fn main() {
     use Amphoreus::ecs::init_global_ecs;
@@ -16,19 +18,22 @@ fn main() {
 
     #[tauri::command]
     fn read_entropy_series(state: tauri::State<'_, SharedObserverSnapshot>) -> Vec<f64> {
-        state.read().entropy_samples
+        state.read().entropy_samples()
     }
 
     init_global_ecs(1_500_000);
 
     let mut engine = AmphoreusEngine::new(256 * 1024 * 1024);
-    engine.seed_world(WorldSeedConfig {
+    if let Err(err) = engine.seed_world(WorldSeedConfig {
         citizens: 20_000,
         titans: 500,
         chrysos_heirs: 128,
-    });
+        ..WorldSeedConfig::default()
+    }) {
+        eprintln!("world seeding fell short: {err}");
+    }
 
-    let runtime = ObserverRuntime::spawn(engine, 60, 600);
+    let (runtime, _commands) = ObserverRuntime::spawn(engine, 60, 600);
     let shared = runtime.shared_snapshot();
 
     tauri::Builder::default()
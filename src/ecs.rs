@@ -1,8 +1,13 @@
-use std::sync::OnceLock;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
 
 use parking_lot::RwLock;
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::rng::SimRng;
+
 pub type Entity = u32;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -14,6 +19,16 @@ pub enum Path {
     None,
 }
 
+impl Path {
+    /// Every path, in declaration order.
+    pub const ALL: [Self; 4] = [
+        Self::Erudition,
+        Self::Destruction,
+        Self::Remembrance,
+        Self::None,
+    ];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Coreflame {
     pub power_level: f64,
@@ -57,8 +72,162 @@ impl Default for GoldenBlood {
     }
 }
 
+/// Scales the corruption an entity's golden blood gains per tick; 0 makes it immune.
+///
+/// Entities without this component corrupt at the default factor of 1.0.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CorruptionResistance {
+    pub factor: f64,
+}
+
+impl Default for CorruptionResistance {
+    fn default() -> Self {
+        Self { factor: 1.0 }
+    }
+}
+
+/// An entity ID tagged with the generation of its slot when the handle was taken.
+///
+/// Slots are reused after despawns and black tides; `SoaEcs::resolve` refuses a handle once
+/// its slot has moved on to another entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EntityHandle {
+    pub index: Entity,
+    pub generation: u32,
+}
+
+/// Seeding group an entity was spawned into, kept as a tag for per-cohort analytics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cohort {
+    Citizen,
+    Titan,
+    ChrysosHeir,
+}
+
+/// City-state or faction an entity belongs to; entities without one are unaffiliated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Faction(pub u16);
+
+/// Cycles an entity has left to live; the engine despawns it once this reaches zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lifespan {
+    pub remaining_cycles: u64,
+}
+
+/// How one cohort fared, counted over its live tagged members.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CohortStats {
+    pub population: usize,
+    /// Members whose coreflame is aligned with Destruction.
+    pub destruction: usize,
+    /// Members whose coreflame has no power left.
+    pub zero_power: usize,
+}
+
+/// Portable copy of a set of entities and their components, keyed by original entity ID.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EcsSnapshot {
+    pub entities: Vec<Entity>,
+    pub coreflames: Vec<(Entity, Coreflame)>,
+    pub memory_logs: Vec<(Entity, MemoryLog)>,
+    pub golden_blood: Vec<(Entity, GoldenBlood)>,
+}
+
+/// Set of component kinds, used to select stores for snapshots and watches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentMask(u8);
+
+impl ComponentMask {
+    pub const NONE: Self = Self(0);
+    pub const COREFLAME: Self = Self(1 << 0);
+    pub const MEMORY_LOG: Self = Self(1 << 1);
+    pub const GOLDEN_BLOOD: Self = Self(1 << 2);
+    pub const ALL: Self = Self(0b111);
+
+    pub fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Component kinds a watch callback is interested in.
+pub type WatchMask = ComponentMask;
+
+impl std::ops::BitOr for ComponentMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Handle returned by `SoaEcs::watch`, used to remove the callback again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WatchId(u64);
+
+type WatchCallback = Box<dyn FnMut(Entity, WatchMask) + Send + Sync>;
+
+struct Watcher {
+    id: WatchId,
+    entity: Entity,
+    mask: WatchMask,
+    callback: WatchCallback,
+}
+
+impl std::fmt::Debug for Watcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watcher")
+            .field("id", &self.id)
+            .field("entity", &self.entity)
+            .field("mask", &self.mask)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A broken sparse/dense invariant found by `ComponentStore::check_invariants`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreError {
+    /// The dense entity and data columns have different lengths.
+    LengthMismatch { entities: usize, data: usize },
+    /// A sparse slot points past the dense arrays or at a different entity.
+    DanglingSlot { entity: Entity, dense_index: usize },
+    /// A dense entry whose entity's sparse slot does not point back at it.
+    BrokenBackPointer { entity: Entity, dense_index: usize },
+}
+
+impl std::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch { entities, data } => {
+                write!(f, "{entities} dense entities but {data} dense components")
+            }
+            Self::DanglingSlot {
+                entity,
+                dense_index,
+            } => write!(
+                f,
+                "entity {entity} maps to dense index {dense_index} it does not hold"
+            ),
+            Self::BrokenBackPointer {
+                entity,
+                dense_index,
+            } => write!(
+                f,
+                "dense index {dense_index} holds entity {entity} but is not mapped"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
 /// Dense/sparse component storage for cache-friendly iteration and O(1) access.
-#[derive(Debug, Default)]
+///
+/// Serializes with its sparse index, so a decoded store is ready to use as is.
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ComponentStore<T> {
     dense_entities: Vec<Entity>,
     dense_data: Vec<T>,
@@ -74,10 +243,12 @@ impl<T> ComponentStore<T> {
         }
     }
 
+    /// Grows the sparse array geometrically, so ascending inserts reallocate O(log n) times.
     fn ensure_sparse_capacity(&mut self, entity: Entity) {
         let index = entity as usize;
         if index >= self.sparse.len() {
-            self.sparse.resize(index + 1, 0);
+            let grown = (index + 1).max(self.sparse.len() * 2).next_power_of_two();
+            self.sparse.resize(grown, 0);
         }
     }
 
@@ -118,6 +289,14 @@ impl<T> ComponentStore<T> {
         self.dense_data.get_mut((slot - 1) as usize)
     }
 
+    /// Whether `entity` has a component, read from its sparse slot alone; entities past the
+    /// end of the sparse array have none.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.sparse
+            .get(entity as usize)
+            .is_some_and(|&slot| slot != 0)
+    }
+
     pub fn remove(&mut self, entity: Entity) -> Option<T> {
         let sparse_index = entity as usize;
         let slot = *self.sparse.get(sparse_index)?;
@@ -146,10 +325,32 @@ impl<T> ComponentStore<T> {
         self.sparse.fill(0);
     }
 
+    /// Keeps only the components of entities `keep` accepts, compacting them in dense order.
+    pub fn retain(&mut self, mut keep: impl FnMut(Entity) -> bool) {
+        let mut kept = 0;
+        for index in 0..self.dense_entities.len() {
+            let entity = self.dense_entities[index];
+            if keep(entity) {
+                self.dense_entities.swap(kept, index);
+                self.dense_data.swap(kept, index);
+                self.sparse[entity as usize] = (kept as u32) + 1;
+                kept += 1;
+            } else {
+                self.sparse[entity as usize] = 0;
+            }
+        }
+        self.dense_entities.truncate(kept);
+        self.dense_data.truncate(kept);
+    }
+
     pub fn len(&self) -> usize {
         self.dense_data.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.dense_data.is_empty()
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> {
         self.dense_entities
             .iter()
@@ -179,45 +380,424 @@ impl<T> ComponentStore<T> {
     pub fn dense_pairs_mut(&mut self) -> (&[Entity], &mut [T]) {
         (&self.dense_entities, &mut self.dense_data)
     }
+
+    /// Entities found in both this store and `other`, with mutable references into each.
+    ///
+    /// Walks the smaller dense set in order and looks each entity up in the other's sparse
+    /// index. Each entity appears once in a dense set, so every component is handed out at
+    /// most once.
+    pub fn join_mut<'a, U>(
+        &'a mut self,
+        other: &'a mut ComponentStore<U>,
+    ) -> impl Iterator<Item = (Entity, &'a mut T, &'a mut U)> {
+        let own_drives = self.len() <= other.len();
+        let (own_driven, other_driven) = if own_drives {
+            (Some(self.drive_join(other)), None)
+        } else {
+            let swapped = other
+                .drive_join(self)
+                .map(|(entity, theirs, own)| (entity, own, theirs));
+            (None, Some(swapped))
+        };
+        own_driven
+            .into_iter()
+            .flatten()
+            .chain(other_driven.into_iter().flatten())
+    }
+
+    /// `join_mut` walking this store's dense set; only `other` needs takeable slots.
+    fn drive_join<'a, U>(
+        &'a mut self,
+        other: &'a mut ComponentStore<U>,
+    ) -> impl Iterator<Item = (Entity, &'a mut T, &'a mut U)> {
+        let other_sparse = &other.sparse;
+        let mut other_slots: Vec<Option<&mut U>> = other.dense_data.iter_mut().map(Some).collect();
+        self.dense_entities
+            .iter()
+            .zip(self.dense_data.iter_mut())
+            .filter_map(move |(&entity, own)| {
+                let slot = *other_sparse.get(entity as usize)?;
+                let theirs = other_slots.get_mut(slot.checked_sub(1)? as usize)?.take()?;
+                Some((entity, own, theirs))
+            })
+    }
+
+    /// Verifies that sparse slots and dense entries point at each other consistently.
+    ///
+    /// Walks every slot and entry, so it is meant for tests and debug builds.
+    pub fn check_invariants(&self) -> Result<(), StoreError> {
+        if self.dense_entities.len() != self.dense_data.len() {
+            return Err(StoreError::LengthMismatch {
+                entities: self.dense_entities.len(),
+                data: self.dense_data.len(),
+            });
+        }
+
+        for (index, &slot) in self.sparse.iter().enumerate() {
+            if slot == 0 {
+                continue;
+            }
+            let dense_index = (slot - 1) as usize;
+            if self.dense_entities.get(dense_index) != Some(&(index as Entity)) {
+                return Err(StoreError::DanglingSlot {
+                    entity: index as Entity,
+                    dense_index,
+                });
+            }
+        }
+
+        for (dense_index, &entity) in self.dense_entities.iter().enumerate() {
+            let slot = self.sparse.get(entity as usize).copied().unwrap_or(0);
+            if slot as usize != dense_index + 1 {
+                return Err(StoreError::BrokenBackPointer {
+                    entity,
+                    dense_index,
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
+/// Behavior shared by every component store backend, so systems can be written once over
+/// either.
+pub trait ComponentStorage<T> {
+    /// Adds or replaces `entity`'s component.
+    fn insert(&mut self, entity: Entity, value: T);
+    fn get(&self, entity: Entity) -> Option<&T>;
+    fn get_mut(&mut self, entity: Entity) -> Option<&mut T>;
+    fn remove(&mut self, entity: Entity) -> Option<T>;
+    fn clear(&mut self);
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn contains(&self, entity: Entity) -> bool {
+        self.get(entity).is_some()
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Entity, &'a T)>
+    where
+        T: 'a;
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (Entity, &'a mut T)>
+    where
+        T: 'a;
+}
+
+impl<T> ComponentStorage<T> for ComponentStore<T> {
+    fn insert(&mut self, entity: Entity, value: T) {
+        ComponentStore::insert(self, entity, value);
+    }
+
+    fn get(&self, entity: Entity) -> Option<&T> {
+        ComponentStore::get(self, entity)
+    }
+
+    fn get_mut(&mut self, entity: Entity) -> Option<&mut T> {
+        ComponentStore::get_mut(self, entity)
+    }
+
+    fn remove(&mut self, entity: Entity) -> Option<T> {
+        ComponentStore::remove(self, entity)
+    }
+
+    fn clear(&mut self) {
+        ComponentStore::clear(self);
+    }
+
+    fn len(&self) -> usize {
+        ComponentStore::len(self)
+    }
+
+    fn contains(&self, entity: Entity) -> bool {
+        ComponentStore::contains(self, entity)
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Entity, &'a T)>
+    where
+        T: 'a,
+    {
+        ComponentStore::iter(self)
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (Entity, &'a mut T)>
+    where
+        T: 'a,
+    {
+        ComponentStore::iter_mut(self)
+    }
+}
+
+/// `HashMap`-backed component storage for components few entities carry.
+///
+/// Memory grows with the number of components rather than the entity span, at the cost
+/// of hashing on every access. Iteration order is unspecified, but the store serializes
+/// in entity order so identical worlds encode to identical bytes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HashComponentStore<T> {
+    #[serde(
+        serialize_with = "serialize_sorted_by_entity",
+        bound(serialize = "T: Serialize")
+    )]
+    components: HashMap<Entity, T>,
+}
+
+fn serialize_sorted_by_entity<T, S>(
+    components: &HashMap<Entity, T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: serde::Serializer,
+{
+    let mut entries: Vec<_> = components.iter().collect();
+    entries.sort_unstable_by_key(|(entity, _)| **entity);
+    serializer.collect_map(entries)
+}
+
+impl<T> HashComponentStore<T> {
+    pub fn with_capacity(component_capacity: usize) -> Self {
+        Self {
+            components: HashMap::with_capacity(component_capacity),
+        }
+    }
+
+    /// Keeps only the components of entities `keep` accepts.
+    pub fn retain(&mut self, mut keep: impl FnMut(Entity) -> bool) {
+        self.components.retain(|&entity, _| keep(entity));
+    }
+}
+
+impl<T> ComponentStorage<T> for HashComponentStore<T> {
+    fn insert(&mut self, entity: Entity, value: T) {
+        self.components.insert(entity, value);
+    }
+
+    fn get(&self, entity: Entity) -> Option<&T> {
+        self.components.get(&entity)
+    }
+
+    fn get_mut(&mut self, entity: Entity) -> Option<&mut T> {
+        self.components.get_mut(&entity)
+    }
+
+    fn remove(&mut self, entity: Entity) -> Option<T> {
+        self.components.remove(&entity)
+    }
+
+    fn clear(&mut self) {
+        self.components.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    fn contains(&self, entity: Entity) -> bool {
+        self.components.contains_key(&entity)
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Entity, &'a T)>
+    where
+        T: 'a,
+    {
+        self.components
+            .iter()
+            .map(|(&entity, value)| (entity, value))
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (Entity, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.components
+            .iter_mut()
+            .map(|(&entity, value)| (entity, value))
+    }
+}
+
+/// What the `EcsWorld` accessors do after a write was interrupted by a panic.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoisonRecovery {
+    /// Wipe the world as a black tide would, then carry on.
+    #[default]
+    Reset,
+    /// Leave the world as the panic left it and refuse writes until `EcsWorld::reset`.
+    Refuse,
+}
+
+/// How many of the latest spawns `SoaEcs::recent_spawns` remembers.
+pub const RECENT_SPAWN_CAPACITY: usize = 64;
+
 /// Core world storage using dense per-component arrays.
-#[derive(Debug)]
+///
+/// Serializing captures the whole world, including ID allocation state and every store's
+/// sparse index; watches are callbacks, so they are skipped and a decoded world has none.
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SoaEcs {
+    id_base: Entity,
+    default_path: Path,
     next_entity: Entity,
+    /// Despawned IDs waiting to be handed out again, most recently freed last.
+    free_entities: Vec<Entity>,
+    spawned_total: u64,
+    /// Holds up to twice `RECENT_SPAWN_CAPACITY` IDs; the newest half is the live window.
+    recent_spawns: Vec<Entity>,
     alive_count: usize,
     alive: Vec<bool>,
+    /// Bumped whenever a slot's entity dies, invalidating handles taken before.
+    generations: Vec<u32>,
+    spawned_at: Vec<u64>,
     pub coreflames: ComponentStore<Coreflame>,
     pub memory_logs: ComponentStore<MemoryLog>,
     pub golden_blood: ComponentStore<GoldenBlood>,
+    /// Cohort tags; not part of `EcsSnapshot`, so snapshots and merges drop them.
+    pub cohorts: ComponentStore<Cohort>,
+    /// Like the cohort tags, not part of `EcsSnapshot`. Only Chrysos heirs carry one, so it
+    /// lives in a hash store rather than a sparse array spanning every entity.
+    pub corruption_resistance: HashComponentStore<CorruptionResistance>,
+    /// Faction tags; like cohorts, not part of `EcsSnapshot`.
+    pub factions: ComponentStore<Faction>,
+    /// Lifespans; also not part of `EcsSnapshot`.
+    pub lifespans: ComponentStore<Lifespan>,
+    #[serde(skip)]
+    watchers: Vec<Watcher>,
+    #[serde(skip)]
+    pending_changes: Vec<(Entity, WatchMask)>,
+    #[serde(skip)]
+    next_watch_id: u64,
+    #[serde(skip)]
+    poison_recovery: PoisonRecovery,
+    /// Set while `EcsWorld::write` runs; still set afterwards only if the write panicked.
+    #[serde(skip)]
+    mutation_interrupted: bool,
 }
 
 impl SoaEcs {
     pub fn with_capacity(entity_capacity: usize) -> Self {
+        Self::with_capacity_and_base(entity_capacity, 0)
+    }
+
+    /// Like `with_capacity`, but entity IDs are allocated from `id_base` upwards, including
+    /// after a black tide, so separate worlds can use disjoint ID ranges.
+    ///
+    /// Storage is indexed by raw entity ID, so the first `id_base` slots of every lookup
+    /// array are allocated but never used.
+    pub fn with_capacity_and_base(entity_capacity: usize, id_base: Entity) -> Self {
+        let entity_span = id_base as usize + entity_capacity;
         Self {
-            next_entity: 0,
+            id_base,
+            default_path: Path::None,
+            next_entity: id_base,
+            free_entities: Vec::new(),
+            spawned_total: 0,
+            recent_spawns: Vec::with_capacity(2 * RECENT_SPAWN_CAPACITY),
             alive_count: 0,
-            alive: vec![false; entity_capacity],
-            coreflames: ComponentStore::with_capacity(entity_capacity, entity_capacity / 4),
-            memory_logs: ComponentStore::with_capacity(entity_capacity, entity_capacity / 8),
-            golden_blood: ComponentStore::with_capacity(entity_capacity, entity_capacity / 4),
+            alive: vec![false; entity_span],
+            generations: vec![0; entity_span],
+            spawned_at: vec![0; entity_span],
+            coreflames: ComponentStore::with_capacity(entity_span, entity_capacity / 4),
+            memory_logs: ComponentStore::with_capacity(entity_span, entity_capacity / 8),
+            golden_blood: ComponentStore::with_capacity(entity_span, entity_capacity / 4),
+            cohorts: ComponentStore::with_capacity(entity_span, entity_capacity / 4),
+            corruption_resistance: HashComponentStore::default(),
+            factions: ComponentStore::with_capacity(entity_span, entity_capacity / 4),
+            lifespans: ComponentStore::with_capacity(entity_span, entity_capacity / 4),
+            watchers: Vec::new(),
+            pending_changes: Vec::new(),
+            next_watch_id: 0,
+            poison_recovery: PoisonRecovery::default(),
+            mutation_interrupted: false,
         }
     }
 
+    /// First entity ID this world allocates.
+    pub fn id_base(&self) -> Entity {
+        self.id_base
+    }
+
+    /// Alignment given by `insert_coreflame_default`; survives black tides.
+    pub fn default_path(&self) -> Path {
+        self.default_path
+    }
+
+    pub fn set_default_path(&mut self, path: Path) {
+        self.default_path = path;
+    }
+
+    /// Recovery applied by the `EcsWorld` accessors after a panicked write.
+    pub fn poison_recovery(&self) -> PoisonRecovery {
+        self.poison_recovery
+    }
+
+    pub fn set_poison_recovery(&mut self, recovery: PoisonRecovery) {
+        self.poison_recovery = recovery;
+    }
+
+    /// Whether an `EcsWorld::write` closure panicked and left this world half-mutated.
+    pub fn is_mutation_interrupted(&self) -> bool {
+        self.mutation_interrupted
+    }
+
+    /// Gives a live `entity` a coreflame of `power_level` aligned with the default path.
+    ///
+    /// Replaces any existing coreflame. Returns false, inserting nothing, for dead entities.
+    pub fn insert_coreflame_default(&mut self, entity: Entity, power_level: f64) -> bool {
+        if !self.is_alive(entity) {
+            return false;
+        }
+        self.coreflames.insert(
+            entity,
+            Coreflame {
+                power_level,
+                alignment: self.default_path,
+            },
+        );
+        true
+    }
+
+    /// Spawns an entity stamped as born at cycle zero.
     pub fn spawn(&mut self) -> Entity {
-        let entity = self.next_entity;
-        self.next_entity = self
-            .next_entity
-            .checked_add(1)
-            .expect("entity id overflowed u32");
+        self.spawn_at_cycle(0)
+    }
+
+    /// Spawns an entity and records `cycle` as its birth for `age_of`.
+    ///
+    /// The most recently despawned ID is reused before a new one is minted, so a raw ID kept
+    /// past its entity's despawn may come to name an unrelated entity; hold an
+    /// `EntityHandle` from `handle` where that matters.
+    pub fn spawn_at_cycle(&mut self, cycle: u64) -> Entity {
+        self.spawned_total += 1;
+        let entity = match self.free_entities.pop() {
+            Some(entity) => entity,
+            None => {
+                let entity = self.next_entity;
+                self.next_entity = self
+                    .next_entity
+                    .checked_add(1)
+                    .expect("entity id overflowed u32");
+                entity
+            }
+        };
 
         let index = entity as usize;
         if index >= self.alive.len() {
             self.alive.resize(index + 1, false);
+            self.generations.resize(index + 1, 0);
+            self.spawned_at.resize(index + 1, 0);
         }
 
         self.alive[index] = true;
+        self.spawned_at[index] = cycle;
         self.alive_count += 1;
+
+        // Shifting once per window keeps the push amortised O(1) and the window contiguous.
+        if self.recent_spawns.len() == 2 * RECENT_SPAWN_CAPACITY {
+            self.recent_spawns.drain(..RECENT_SPAWN_CAPACITY);
+        }
+        self.recent_spawns.push(entity);
         entity
     }
 
@@ -227,10 +807,126 @@ impl SoaEcs {
         }
 
         self.alive[entity as usize] = false;
+        self.generations[entity as usize] = self.generations[entity as usize].wrapping_add(1);
         self.alive_count = self.alive_count.saturating_sub(1);
+        self.free_entities.push(entity);
         self.coreflames.remove(entity);
         self.memory_logs.remove(entity);
         self.golden_blood.remove(entity);
+        self.cohorts.remove(entity);
+        self.corruption_resistance.remove(entity);
+        self.factions.remove(entity);
+        self.lifespans.remove(entity);
+        self.watchers.retain(|watcher| watcher.entity != entity);
+        self.pending_changes
+            .retain(|(changed, _)| *changed != entity);
+        true
+    }
+
+    /// Spawns since this world was created or last wiped, recycled IDs included.
+    pub fn spawned_total(&self) -> u64 {
+        self.spawned_total
+    }
+
+    /// The last `RECENT_SPAWN_CAPACITY` spawned IDs, oldest first.
+    ///
+    /// Entries are not removed on despawn, so check `is_alive` before using one.
+    pub fn recent_spawns(&self) -> &[Entity] {
+        let start = self
+            .recent_spawns
+            .len()
+            .saturating_sub(RECENT_SPAWN_CAPACITY);
+        &self.recent_spawns[start..]
+    }
+
+    /// Despawned IDs that the next spawns will reuse.
+    pub fn free_count(&self) -> usize {
+        self.free_entities.len()
+    }
+
+    /// Registers `callback` to fire when any component in `mask` changes on `entity`.
+    ///
+    /// Only tracked mutations notify: the `modify_*` helpers and the engine passes, which
+    /// call `mark_changed`. Notifications are queued and delivered once per change by
+    /// `flush_watch_events`, so parallel passes never invoke callbacks mid-iteration.
+    /// Callbacks run while the ECS is borrowed and must not reach back into the same world.
+    /// Watches end when their entity is despawned or the world is wiped.
+    pub fn watch(
+        &mut self,
+        entity: Entity,
+        mask: WatchMask,
+        callback: impl FnMut(Entity, WatchMask) + Send + Sync + 'static,
+    ) -> WatchId {
+        let id = WatchId(self.next_watch_id);
+        self.next_watch_id += 1;
+        self.watchers.push(Watcher {
+            id,
+            entity,
+            mask,
+            callback: Box::new(callback),
+        });
+        id
+    }
+
+    pub fn unwatch(&mut self, id: WatchId) -> bool {
+        let before = self.watchers.len();
+        self.watchers.retain(|watcher| watcher.id != id);
+        self.watchers.len() != before
+    }
+
+    /// Queues a change notification for `entity` if anything watches those components.
+    pub fn mark_changed(&mut self, entity: Entity, changed: WatchMask) {
+        if self
+            .watchers
+            .iter()
+            .any(|watcher| watcher.entity == entity && watcher.mask.intersects(changed))
+        {
+            self.pending_changes.push((entity, changed));
+        }
+    }
+
+    /// Delivers every queued change to the matching watch callbacks.
+    pub fn flush_watch_events(&mut self) {
+        for (entity, changed) in std::mem::take(&mut self.pending_changes) {
+            for watcher in &mut self.watchers {
+                if watcher.entity == entity && watcher.mask.intersects(changed) {
+                    (watcher.callback)(entity, changed);
+                }
+            }
+        }
+    }
+
+    /// Mutates `entity`'s coreflame in place and records the change for watchers.
+    pub fn modify_coreflame(&mut self, entity: Entity, f: impl FnOnce(&mut Coreflame)) -> bool {
+        let Some(coreflame) = self.coreflames.get_mut(entity) else {
+            return false;
+        };
+        f(coreflame);
+        self.mark_changed(entity, WatchMask::COREFLAME);
+        true
+    }
+
+    /// Mutates `entity`'s memory log in place and records the change for watchers.
+    pub fn modify_memory_log(&mut self, entity: Entity, f: impl FnOnce(&mut MemoryLog)) -> bool {
+        let Some(memory_log) = self.memory_logs.get_mut(entity) else {
+            return false;
+        };
+        f(memory_log);
+        self.mark_changed(entity, WatchMask::MEMORY_LOG);
+        true
+    }
+
+    /// Mutates `entity`'s golden blood in place and records the change for watchers.
+    pub fn modify_golden_blood(
+        &mut self,
+        entity: Entity,
+        f: impl FnOnce(&mut GoldenBlood),
+    ) -> bool {
+        let Some(golden_blood) = self.golden_blood.get_mut(entity) else {
+            return false;
+        };
+        f(golden_blood);
+        self.mark_changed(entity, WatchMask::GOLDEN_BLOOD);
         true
     }
 
@@ -238,6 +934,97 @@ impl SoaEcs {
         self.alive.get(entity as usize).copied().unwrap_or(false)
     }
 
+    pub fn has_coreflame(&self, entity: Entity) -> bool {
+        self.coreflames.contains(entity)
+    }
+
+    pub fn has_memory_log(&self, entity: Entity) -> bool {
+        self.memory_logs.contains(entity)
+    }
+
+    pub fn has_golden_blood(&self, entity: Entity) -> bool {
+        self.golden_blood.contains(entity)
+    }
+
+    /// Handle to a live `entity` that stops resolving once the entity dies.
+    pub fn handle(&self, entity: Entity) -> Option<EntityHandle> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        Some(EntityHandle {
+            index: entity,
+            generation: self.generations[entity as usize],
+        })
+    }
+
+    /// The entity `handle` names, if it is still alive and its slot has not been reused.
+    pub fn resolve(&self, handle: EntityHandle) -> Option<Entity> {
+        let current = self.generations.get(handle.index as usize).copied()?;
+        (current == handle.generation && self.is_alive(handle.index)).then_some(handle.index)
+    }
+
+    pub fn is_handle_alive(&self, handle: EntityHandle) -> bool {
+        self.resolve(handle).is_some()
+    }
+
+    /// Every entity carrying both a coreflame and golden blood, with both components.
+    ///
+    /// Walks the smaller store in dense order and looks each entity up in the other's sparse
+    /// index.
+    pub fn query_coreflame_golden(
+        &self,
+    ) -> impl Iterator<Item = (Entity, &Coreflame, &GoldenBlood)> {
+        let (coreflames, golden_blood) = (&self.coreflames, &self.golden_blood);
+        let driver = if coreflames.len() <= golden_blood.len() {
+            coreflames.dense_entities()
+        } else {
+            golden_blood.dense_entities()
+        };
+        driver.iter().filter_map(move |&entity| {
+            Some((entity, coreflames.get(entity)?, golden_blood.get(entity)?))
+        })
+    }
+
+    /// Mutable form of `query_coreflame_golden`, visiting entities in the same order.
+    pub fn query_coreflame_golden_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, &mut Coreflame, &mut GoldenBlood)> {
+        self.coreflames.join_mut(&mut self.golden_blood)
+    }
+
+    /// Picks up to `n` distinct live entities by reservoir sampling, in ascending ID order.
+    ///
+    /// The same seed over the same world yields the same sample. Fewer than `n` entities
+    /// come back only when fewer than `n` are alive.
+    pub fn sample_entities(&self, n: usize, rng_seed: u64) -> Vec<Entity> {
+        let mut rng = SimRng::new(rng_seed);
+        let mut reservoir = Vec::with_capacity(n.min(self.alive_count));
+        let live = (self.id_base..self.next_entity).filter(|&entity| self.is_alive(entity));
+        for (seen, entity) in live.enumerate() {
+            if seen < n {
+                reservoir.push(entity);
+                continue;
+            }
+
+            let slot = rng.next_below(seen as u64 + 1) as usize;
+            if slot < n {
+                reservoir[slot] = entity;
+            }
+        }
+        reservoir.sort_unstable();
+        reservoir
+    }
+
+    /// Cycles survived by a live entity as of `current_cycle`.
+    pub fn age_of(&self, entity: Entity, current_cycle: u64) -> Option<u64> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+
+        let spawned_at = self.spawned_at.get(entity as usize).copied()?;
+        Some(current_cycle.saturating_sub(spawned_at))
+    }
+
     pub fn entity_count(&self) -> usize {
         self.alive_count
     }
@@ -246,44 +1033,1439 @@ impl SoaEcs {
         self.alive.len()
     }
 
+    /// Sum of every golden-blood `corruption_level`, in storage order.
+    pub fn total_corruption(&self) -> f64 {
+        self.golden_blood
+            .iter()
+            .map(|(_, blood)| blood.corruption_level)
+            .sum()
+    }
+
     pub fn average_corruption(&self) -> f64 {
         let count = self.golden_blood.len();
         if count == 0 {
             return 0.0;
         }
 
-        let total: f64 = self
+        self.total_corruption() / count as f64
+    }
+
+    /// Entities whose golden-blood corruption lies within `epsilon` of `level`, in ascending ID
+    /// order.
+    ///
+    /// The bound is inclusive and `epsilon` is taken by magnitude, so `0.0` asks for exact
+    /// matches. A NaN `level` or `epsilon` matches nothing.
+    pub fn entities_with_corruption(&self, level: f64, epsilon: f64) -> Vec<Entity> {
+        let epsilon = epsilon.abs();
+        let mut matches: Vec<Entity> = self
             .golden_blood
             .iter()
-            .map(|(_, blood)| blood.corruption_level)
+            .filter(|(_, blood)| (blood.corruption_level - level).abs() <= epsilon)
+            .map(|(entity, _)| entity)
+            .collect();
+        matches.sort_unstable();
+        matches
+    }
+
+    /// Danger posed by `entity`: power amplified by corruption, weighted up for Destruction.
+    ///
+    /// `power * (1 + corruption) * w`, where `w` is 1.5 for Destruction-aligned coreflames
+    /// and 1.0 otherwise. Entities without a coreflame pose no threat and return `None`.
+    pub fn threat_score(&self, entity: Entity) -> Option<f64> {
+        let coreflame = self.coreflames.get(entity)?;
+        let corruption = self
+            .golden_blood
+            .get(entity)
+            .map(|blood| blood.corruption_level)
+            .unwrap_or(0.0);
+        let alignment_weight = if coreflame.alignment == Path::Destruction {
+            1.5
+        } else {
+            1.0
+        };
+        Some(coreflame.power_level * (1.0 + corruption) * alignment_weight)
+    }
+
+    /// Copies every live entity and its components into an `EcsSnapshot`.
+    pub fn snapshot(&self) -> EcsSnapshot {
+        self.snapshot_with(ComponentMask::ALL)
+    }
+
+    /// Copies every live entity, keeping only the component stores selected by `mask`.
+    pub fn snapshot_with(&self, mask: ComponentMask) -> EcsSnapshot {
+        let entities: Vec<Entity> = (0..self.alive.len() as Entity)
+            .filter(|&entity| self.is_alive(entity))
+            .collect();
+        self.snapshot_entities_with(&entities, mask)
+    }
+
+    /// Copies the listed live entities and their components into an `EcsSnapshot`.
+    pub fn snapshot_entities(&self, entities: &[Entity]) -> EcsSnapshot {
+        self.snapshot_entities_with(entities, ComponentMask::ALL)
+    }
+
+    /// Copies the listed live entities, keeping only the component stores selected by `mask`.
+    pub fn snapshot_entities_with(&self, entities: &[Entity], mask: ComponentMask) -> EcsSnapshot {
+        let mut snapshot = EcsSnapshot::default();
+        for &entity in entities {
+            if !self.is_alive(entity) {
+                continue;
+            }
+
+            snapshot.entities.push(entity);
+            if mask.contains(ComponentMask::COREFLAME)
+                && let Some(coreflame) = self.coreflames.get(entity)
+            {
+                snapshot.coreflames.push((entity, *coreflame));
+            }
+            if mask.contains(ComponentMask::MEMORY_LOG)
+                && let Some(memory_log) = self.memory_logs.get(entity)
+            {
+                snapshot.memory_logs.push((entity, *memory_log));
+            }
+            if mask.contains(ComponentMask::GOLDEN_BLOOD)
+                && let Some(golden_blood) = self.golden_blood.get(entity)
+            {
+                snapshot.golden_blood.push((entity, *golden_blood));
+            }
+        }
+        snapshot
+    }
+
+    /// Replaces the whole world with `snapshot`, keeping its entity IDs.
+    ///
+    /// Stores the snapshot omitted come back empty. Watches are dropped with the old world.
+    pub fn restore_snapshot(&mut self, snapshot: &EcsSnapshot) {
+        self.clear_for_black_tide();
+        for &entity in &snapshot.entities {
+            let index = entity as usize;
+            if index >= self.alive.len() {
+                self.alive.resize(index + 1, false);
+                self.generations.resize(index + 1, 0);
+                self.spawned_at.resize(index + 1, 0);
+            }
+            if !self.alive[index] {
+                self.alive[index] = true;
+                self.alive_count += 1;
+            }
+            self.next_entity = self.next_entity.max(entity.saturating_add(1));
+        }
+        // Gaps between restored IDs are handed out first, lowest ID first.
+        self.free_entities = (self.id_base..self.next_entity)
+            .rev()
+            .filter(|&entity| !self.is_alive(entity))
+            .collect();
+
+        for &(entity, coreflame) in &snapshot.coreflames {
+            if self.is_alive(entity) {
+                self.coreflames.insert(entity, coreflame);
+            }
+        }
+        for &(entity, memory_log) in &snapshot.memory_logs {
+            if self.is_alive(entity) {
+                self.memory_logs.insert(entity, memory_log);
+            }
+        }
+        for &(entity, golden_blood) in &snapshot.golden_blood {
+            if self.is_alive(entity) {
+                self.golden_blood.insert(entity, golden_blood);
+            }
+        }
+    }
+
+    /// Spawns every entity of `other` into this world under fresh IDs and copies its components.
+    ///
+    /// Existing entities are untouched. Returns the mapping from `other`'s IDs to the new ones;
+    /// components whose entity is not listed in `other.entities` are skipped.
+    pub fn merge_from(&mut self, other: &EcsSnapshot) -> HashMap<Entity, Entity> {
+        let mut remap = HashMap::with_capacity(other.entities.len());
+        for &entity in &other.entities {
+            remap.entry(entity).or_insert_with(|| self.spawn());
+        }
+
+        for (entity, coreflame) in &other.coreflames {
+            if let Some(&spawned) = remap.get(entity) {
+                self.coreflames.insert(spawned, *coreflame);
+            }
+        }
+        for (entity, memory_log) in &other.memory_logs {
+            if let Some(&spawned) = remap.get(entity) {
+                self.memory_logs.insert(spawned, *memory_log);
+            }
+        }
+        for (entity, golden_blood) in &other.golden_blood {
+            if let Some(&spawned) = remap.get(entity) {
+                self.golden_blood.insert(spawned, *golden_blood);
+            }
+        }
+        remap
+    }
+
+    /// Adds one survived tick to the `retained_cycles` of every memory log but those of
+    /// `preserved`.
+    ///
+    /// Counters start over naturally when an entity dies, since its memory log goes with it.
+    pub fn age_memory_logs(&mut self, preserved: &[Entity]) {
+        let (entities, memory_logs) = self.memory_logs.dense_pairs_mut();
+        for (&entity, memory_log) in entities.iter().zip(memory_logs) {
+            if !preserved.contains(&entity) {
+                memory_log.retained_cycles = memory_log.retained_cycles.saturating_add(1);
+            }
+        }
+
+        if self.watchers.is_empty() {
+            return;
+        }
+        let aged: Vec<Entity> = self
+            .memory_logs
+            .dense_entities()
+            .iter()
+            .copied()
+            .filter(|entity| !preserved.contains(entity))
+            .collect();
+        for entity in aged {
+            self.mark_changed(entity, WatchMask::MEMORY_LOG);
+        }
+    }
+
+    /// Takes a cycle off every lifespan and appends the entities whose lifespan ran out to
+    /// `expired`; despawning them is left to the caller, since this walks the dense store.
+    pub fn age_lifespans(&mut self, expired: &mut Vec<Entity>) {
+        let (entities, lifespans) = self.lifespans.dense_pairs_mut();
+        for (&entity, lifespan) in entities.iter().zip(lifespans) {
+            lifespan.remaining_cycles = lifespan.remaining_cycles.saturating_sub(1);
+            if lifespan.remaining_cycles == 0 {
+                expired.push(entity);
+            }
+        }
+    }
+
+    /// Uniformly rescales golden-blood corruption so the world average approaches `target`.
+    ///
+    /// Levels stay clamped to `[0.0, 1.0]`. Scaling up saturates the most corrupted entities
+    /// first, so the scale is searched until the clamped average lands on the target (or every
+    /// tainted entity is saturated). A world with no corruption at all cannot be scaled and is
+    /// left untouched. Returns the average actually reached.
+    pub fn set_average_corruption(&mut self, target: f64) -> f64 {
+        const SCALE_SEARCH_STEPS: usize = 64;
+        let target = target.clamp(0.0, 1.0);
+        let current = self.average_corruption();
+        if current <= 0.0 {
+            return current;
+        }
+
+        let levels = self.golden_blood.dense_data();
+        let scale = if target <= current {
+            target / current
+        } else {
+            let count = levels.len() as f64;
+            let clamped_average = |scale: f64| {
+                levels
+                    .iter()
+                    .map(|blood| (blood.corruption_level * scale).min(1.0))
+                    .sum::<f64>()
+                    / count
+            };
+            let least_tainted = levels
+                .iter()
+                .map(|blood| blood.corruption_level)
+                .filter(|level| *level > 0.0)
+                .fold(f64::INFINITY, f64::min);
+
+            let (mut low, mut high) = (1.0, 1.0 / least_tainted);
+            for _ in 0..SCALE_SEARCH_STEPS {
+                let mid = 0.5 * (low + high);
+                if clamped_average(mid) < target {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            high
+        };
+
+        for blood in self.golden_blood.dense_data_mut() {
+            blood.corruption_level = (blood.corruption_level * scale).clamp(0.0, 1.0);
+        }
+        self.average_corruption()
+    }
+
+    /// Sums every coreflame `power_level` as a civilization-strength metric.
+    ///
+    /// Partial sums cover fixed-size chunks and are added in chunk order, so
+    /// the total is bit-identical whatever the thread count.
+    pub fn total_power(&self) -> f64 {
+        const CHUNK: usize = 4096;
+        let coreflames = self.coreflames.dense_data();
+        let chunk_sum = |chunk: &[Coreflame]| -> f64 {
+            chunk.iter().map(|coreflame| coreflame.power_level).sum()
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let partials: Vec<f64> = coreflames.par_chunks(CHUNK).map(chunk_sum).collect();
+        #[cfg(target_arch = "wasm32")]
+        let partials: Vec<f64> = coreflames.chunks(CHUNK).map(chunk_sum).collect();
+
+        partials.iter().sum()
+    }
+
+    /// Number of coreflames aligned with each path, in `Path::ALL` order.
+    pub fn count_by_path(&self) -> [(Path, usize); 4] {
+        let mut counts = Path::ALL.map(|path| (path, 0));
+        for coreflame in self.coreflames.dense_data() {
+            let slot = match coreflame.alignment {
+                Path::Erudition => 0,
+                Path::Destruction => 1,
+                Path::Remembrance => 2,
+                Path::None => 3,
+            };
+            counts[slot].1 += 1;
+        }
+        counts
+    }
+
+    /// Path held by the most coreflames; ties go to the path declared first in `Path`.
+    ///
+    /// A world without coreflames has no dominant path and returns `Path::None`.
+    pub fn dominant_path(&self) -> Path {
+        let mut dominant = (Path::None, 0);
+        for (path, count) in self.count_by_path() {
+            if count > dominant.1 {
+                dominant = (path, count);
+            }
+        }
+        dominant.0
+    }
+
+    /// Population, Destruction-aligned, and zero-power counts for the members of `cohort`.
+    pub fn cohort_stats(&self, cohort: Cohort) -> CohortStats {
+        let mut stats = CohortStats::default();
+        for (entity, _) in self.cohorts.iter().filter(|(_, tag)| **tag == cohort) {
+            stats.population += 1;
+            if let Some(coreflame) = self.coreflames.get(entity) {
+                stats.destruction += usize::from(coreflame.alignment == Path::Destruction);
+                stats.zero_power += usize::from(coreflame.power_level <= 0.0);
+            }
+        }
+        stats
+    }
+
+    /// Gini coefficient of coreflame `power_level`s: 0 for perfect equality, approaching 1
+    /// when a few entities hold all the power.
+    ///
+    /// Uses `G = 2·Σ(i·xᵢ) / (n·Σxᵢ) − (n + 1) / n` over powers sorted ascending, `i` from 1.
+    /// Fewer than two coreflames, or no power at all, yield 0.
+    pub fn power_gini(&self) -> f64 {
+        let mut powers: Vec<f64> = self
+            .coreflames
+            .dense_data()
+            .iter()
+            .map(|coreflame| coreflame.power_level)
+            .collect();
+        if powers.len() < 2 {
+            return 0.0;
+        }
+        powers.sort_unstable_by(f64::total_cmp);
+
+        let total: f64 = powers.iter().sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+
+        let n = powers.len() as f64;
+        let weighted: f64 = powers
+            .iter()
+            .enumerate()
+            .map(|(index, power)| (index + 1) as f64 * power)
             .sum();
-        total / count as f64
+        (2.0 * weighted) / (n * total) - (n + 1.0) / n
     }
 
     pub fn clear_for_black_tide(&mut self) {
-        self.next_entity = 0;
+        self.next_entity = self.id_base;
+        self.free_entities.clear();
+        self.spawned_total = 0;
+        self.recent_spawns.clear();
         self.alive_count = 0;
-        self.alive.fill(false);
+        for (alive, generation) in self.alive.iter_mut().zip(&mut self.generations) {
+            if *alive {
+                *alive = false;
+                *generation = generation.wrapping_add(1);
+            }
+        }
+        self.spawned_at.fill(0);
         self.coreflames.clear();
         self.memory_logs.clear();
         self.golden_blood.clear();
+        self.cohorts.clear();
+        self.corruption_resistance.clear();
+        self.factions.clear();
+        self.lifespans.clear();
+        self.watchers.clear();
+        self.pending_changes.clear();
+    }
+
+    /// Wipes the world like `clear_for_black_tide`, except that the live entities in `keep`
+    /// survive with their IDs, generations, birth cycles, components, and watches, so their
+    /// handles still resolve. Dead IDs in `keep` are ignored.
+    ///
+    /// Every other component is dropped from the dense arrays. Freed IDs below the highest
+    /// survivor are reused lowest first, and `spawned_total` restarts at the survivor count.
+    pub fn clear_except(&mut self, keep: &[Entity]) {
+        let mut kept = vec![false; self.alive.len()];
+        for &entity in keep {
+            if self.is_alive(entity) {
+                kept[entity as usize] = true;
+            }
+        }
+        let is_kept = |entity: Entity| kept.get(entity as usize).copied().unwrap_or(false);
+
+        let mut next_entity = self.id_base;
+        self.alive_count = 0;
+        for (index, ((alive, generation), spawned_at)) in self
+            .alive
+            .iter_mut()
+            .zip(&mut self.generations)
+            .zip(&mut self.spawned_at)
+            .enumerate()
+        {
+            if kept[index] {
+                self.alive_count += 1;
+                next_entity = index as Entity + 1;
+                continue;
+            }
+            if *alive {
+                *alive = false;
+                *generation = generation.wrapping_add(1);
+            }
+            *spawned_at = 0;
+        }
+        self.next_entity = next_entity;
+        self.free_entities = (self.id_base..next_entity)
+            .rev()
+            .filter(|&entity| !is_kept(entity))
+            .collect();
+        self.spawned_total = self.alive_count as u64;
+        self.recent_spawns.retain(|&entity| is_kept(entity));
+
+        self.coreflames.retain(is_kept);
+        self.memory_logs.retain(is_kept);
+        self.golden_blood.retain(is_kept);
+        self.cohorts.retain(is_kept);
+        self.corruption_resistance.retain(is_kept);
+        self.factions.retain(is_kept);
+        self.lifespans.retain(is_kept);
+        self.watchers.retain(|watcher| is_kept(watcher.entity));
+        self.pending_changes.retain(|&(entity, _)| is_kept(entity));
+    }
+}
+
+/// Shared handle to one `SoaEcs` world; clones refer to the same world.
+///
+/// Every engine owns one, so several engines can run side by side in one process. The
+/// accessors recover from panicked writes according to the world's `PoisonRecovery`.
+#[derive(Clone)]
+pub struct EcsWorld {
+    inner: Arc<RwLock<SoaEcs>>,
+}
+
+impl EcsWorld {
+    pub fn new(entity_capacity: usize) -> Self {
+        Self::from_ecs(SoaEcs::with_capacity(entity_capacity))
+    }
+
+    pub fn from_ecs(ecs: SoaEcs) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(ecs)),
+        }
+    }
+
+    /// Reads the world; after a panicked write under `PoisonRecovery::Reset` the world is
+    /// wiped first, while under `Refuse` the half-mutated world is shown as it is.
+    pub fn read<R>(&self, f: impl FnOnce(&SoaEcs) -> R) -> R {
+        {
+            let guard = self.inner.read();
+            if !guard.mutation_interrupted || guard.poison_recovery == PoisonRecovery::Refuse {
+                return f(&guard);
+            }
+        }
+        let mut guard = self.inner.write();
+        recover_interrupted(&mut guard);
+        f(&guard)
+    }
+
+    /// Mutates the world, recovering first if an earlier write panicked.
+    ///
+    /// Returns `None` when an earlier write panicked under `PoisonRecovery::Refuse`.
+    pub fn write<R>(&self, f: impl FnOnce(&mut SoaEcs) -> R) -> Option<R> {
+        let mut guard = self.inner.write();
+        if !recover_interrupted(&mut guard) {
+            return None;
+        }
+
+        guard.mutation_interrupted = true;
+        let result = f(&mut guard);
+        guard.mutation_interrupted = false;
+        Some(result)
+    }
+
+    /// Wipes the world and clears any interrupted-write mark, whatever the recovery policy.
+    pub fn reset(&self) {
+        let mut guard = self.inner.write();
+        guard.clear_for_black_tide();
+        guard.mutation_interrupted = false;
+    }
+
+    /// Whether `self` and `other` are handles to the same world.
+    pub fn same_world(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
     }
 }
 
-static GLOBAL_ECS: OnceLock<RwLock<SoaEcs>> = OnceLock::new();
+static GLOBAL_ECS: OnceLock<EcsWorld> = OnceLock::new();
 
+/// Creates the process-wide default world with room for `entity_capacity` entities.
+///
+/// Has no effect once the default world exists, including when `global_world` created it.
 pub fn init_global_ecs(entity_capacity: usize) {
-    let _ = GLOBAL_ECS.set(RwLock::new(SoaEcs::with_capacity(entity_capacity)));
+    let _ = GLOBAL_ECS.set(EcsWorld::new(entity_capacity));
 }
 
+/// Handle to the process-wide default world, creating an empty one if none exists yet.
+///
+/// `AmphoreusEngine::new` and `with_config` share this world; use
+/// `AmphoreusEngine::with_world` for an independent one.
+pub fn global_world() -> EcsWorld {
+    GLOBAL_ECS.get_or_init(|| EcsWorld::new(0)).clone()
+}
+
+/// `EcsWorld::read` on the default world; `None` until `init_global_ecs` or `global_world`
+/// has created it.
 pub fn with_global_ecs<R>(f: impl FnOnce(&SoaEcs) -> R) -> Option<R> {
-    let lock = GLOBAL_ECS.get()?;
-    let guard = lock.read();
-    Some(f(&guard))
+    Some(GLOBAL_ECS.get()?.read(f))
 }
 
+/// `EcsWorld::write` on the default world.
+///
+/// Returns `None` when the default world does not exist yet, or when an earlier write
+/// panicked under `PoisonRecovery::Refuse`.
 pub fn with_global_ecs_mut<R>(f: impl FnOnce(&mut SoaEcs) -> R) -> Option<R> {
-    let lock = GLOBAL_ECS.get()?;
-    let mut guard = lock.write();
-    Some(f(&mut guard))
+    GLOBAL_ECS.get()?.write(f)
+}
+
+/// `EcsWorld::reset` on the default world. Returns false when it does not exist yet.
+pub fn reset_global_ecs() -> bool {
+    let Some(world) = GLOBAL_ECS.get() else {
+        return false;
+    };
+    world.reset();
+    true
+}
+
+/// Applies the recovery policy to an interrupted world; returns whether it is usable.
+fn recover_interrupted(ecs: &mut SoaEcs) -> bool {
+    if !ecs.mutation_interrupted {
+        return true;
+    }
+    match ecs.poison_recovery {
+        PoisonRecovery::Reset => {
+            ecs.clear_for_black_tide();
+            ecs.mutation_interrupted = false;
+            true
+        }
+        PoisonRecovery::Refuse => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    use bincode::config::standard;
+    use bincode::serde::{decode_from_slice, encode_to_vec};
+
+    use super::*;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn total_power_does_not_depend_on_thread_count() {
+        let mut ecs = SoaEcs::with_capacity(0);
+        for idx in 0..50_000_u32 {
+            let entity = ecs.spawn();
+            ecs.coreflames.insert(
+                entity,
+                Coreflame {
+                    power_level: 1.0 / (1.0 + idx as f64) + (idx % 7) as f64 * 1e-3,
+                    alignment: Path::Erudition,
+                },
+            );
+        }
+
+        let totals: Vec<u64> = [1, 3, 8]
+            .into_iter()
+            .map(|threads| {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .expect("pool builds")
+                    .install(|| ecs.total_power().to_bits())
+            })
+            .collect();
+        assert!(totals.windows(2).all(|pair| pair[0] == pair[1]));
+        assert_eq!(SoaEcs::with_capacity(0).total_power(), 0.0);
+    }
+
+    fn blood_world(levels: &[f64]) -> SoaEcs {
+        let mut ecs = SoaEcs::with_capacity(0);
+        for &corruption_level in levels {
+            let entity = ecs.spawn();
+            ecs.golden_blood
+                .insert(entity, GoldenBlood { corruption_level });
+        }
+        ecs
+    }
+
+    #[test]
+    fn set_average_corruption_hits_the_target_within_tolerance() {
+        let mut ecs = blood_world(&[0.1, 0.2, 0.3, 0.4]);
+        let reached = ecs.set_average_corruption(0.5);
+        assert!((reached - 0.5).abs() < 1e-12);
+        assert!((ecs.average_corruption() - 0.5).abs() < 1e-12);
+        assert!((ecs.golden_blood.get(3).unwrap().corruption_level - 0.8).abs() < 1e-12);
+
+        let reached = ecs.set_average_corruption(0.05);
+        assert!((reached - 0.05).abs() < 1e-12);
+
+        // Saturated entities stop at 1.0 and the rest absorb the difference.
+        let mut ecs = blood_world(&[0.1, 0.9]);
+        let reached = ecs.set_average_corruption(0.8);
+        assert!((reached - 0.8).abs() < 1e-9, "reached {reached}");
+        assert_eq!(ecs.golden_blood.get(1).unwrap().corruption_level, 1.0);
+
+        // Past the point where every tainted entity saturates, the closest
+        // reachable average is returned.
+        let mut ecs = blood_world(&[0.0, 0.5]);
+        assert!((ecs.set_average_corruption(0.9) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn set_average_corruption_leaves_an_uncorrupted_world_alone() {
+        let mut ecs = blood_world(&[0.0, 0.0, 0.0]);
+        assert_eq!(ecs.set_average_corruption(0.6), 0.0);
+        assert!(
+            ecs.golden_blood
+                .iter()
+                .all(|(_, blood)| blood.corruption_level == 0.0)
+        );
+        assert_eq!(SoaEcs::with_capacity(0).set_average_corruption(0.6), 0.0);
+    }
+
+    /// A watch callback that records every notification it receives.
+    fn recording_watch(
+        ecs: &mut SoaEcs,
+        entity: Entity,
+        mask: WatchMask,
+    ) -> std::sync::Arc<parking_lot::Mutex<Vec<(Entity, WatchMask)>>> {
+        let events = std::sync::Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&events);
+        ecs.watch(entity, mask, move |entity, changed| {
+            sink.lock().push((entity, changed))
+        });
+        events
+    }
+
+    #[test]
+    fn watch_fires_once_per_tracked_change_after_a_flush() {
+        let mut ecs = SoaEcs::with_capacity(0);
+        let watched = ecs.spawn();
+        let other = ecs.spawn();
+        for entity in [watched, other] {
+            ecs.coreflames.insert(entity, Coreflame::default());
+            ecs.golden_blood.insert(entity, GoldenBlood::default());
+        }
+        let events = recording_watch(&mut ecs, watched, WatchMask::COREFLAME);
+
+        assert!(ecs.modify_coreflame(watched, |flame| flame.power_level = 0.5));
+        assert!(ecs.modify_coreflame(watched, |flame| flame.power_level = 0.25));
+        assert!(ecs.modify_golden_blood(watched, |blood| blood.corruption_level = 0.5));
+        assert!(ecs.modify_coreflame(other, |flame| flame.power_level = 0.5));
+        assert!(events.lock().is_empty());
+
+        ecs.flush_watch_events();
+        assert_eq!(
+            *events.lock(),
+            [
+                (watched, WatchMask::COREFLAME),
+                (watched, WatchMask::COREFLAME)
+            ]
+        );
+        ecs.flush_watch_events();
+        assert_eq!(events.lock().len(), 2);
+
+        assert!(ecs.despawn(watched));
+        ecs.mark_changed(watched, WatchMask::COREFLAME);
+        ecs.flush_watch_events();
+        assert_eq!(events.lock().len(), 2);
+    }
+
+    /// A world with every mix of components, spawned across a spread of cycles.
+    fn mixed_world(entities: u32) -> SoaEcs {
+        let mut ecs = SoaEcs::with_capacity(entities as usize);
+        for i in 0..entities {
+            let entity = ecs.spawn_at_cycle(u64::from(i % 17));
+            let level = f64::from(i % 100) / 100.0;
+            if i.is_multiple_of(2) {
+                let alignment =
+                    [Path::Erudition, Path::Destruction, Path::Remembrance][i as usize % 3];
+                ecs.coreflames.insert(
+                    entity,
+                    Coreflame {
+                        power_level: level * 2.0,
+                        alignment,
+                    },
+                );
+            }
+            if i.is_multiple_of(3) {
+                ecs.memory_logs.insert(
+                    entity,
+                    MemoryLog {
+                        retained_cycles: u64::from(i),
+                        trauma_index: level,
+                    },
+                );
+            }
+            if !i.is_multiple_of(5) {
+                ecs.golden_blood.insert(
+                    entity,
+                    GoldenBlood {
+                        corruption_level: level,
+                    },
+                );
+            }
+        }
+        ecs
+    }
+
+    #[test]
+    fn coreflame_only_snapshot_restores_without_memory_or_blood() {
+        let source = mixed_world(1_000);
+        let partial = source.snapshot_with(ComponentMask::COREFLAME);
+        let full_bytes = encode_to_vec(source.snapshot(), standard()).expect("snapshot encodes");
+        let partial_bytes = encode_to_vec(&partial, standard()).expect("snapshot encodes");
+        assert!(partial_bytes.len() < full_bytes.len());
+        let (decoded, _): (EcsSnapshot, usize) =
+            decode_from_slice(&partial_bytes, standard()).expect("snapshot decodes");
+
+        let mut restored = SoaEcs::with_capacity(0);
+        restored.restore_snapshot(&decoded);
+        assert!(restored.memory_logs.is_empty());
+        assert!(restored.golden_blood.is_empty());
+        assert_eq!(restored.entity_count(), source.entity_count());
+        assert_eq!(restored.coreflames.len(), source.coreflames.len());
+        for (entity, coreflame) in source.coreflames.iter() {
+            assert_eq!(restored.coreflames.get(entity), Some(coreflame));
+        }
+    }
+
+    #[test]
+    fn entities_with_corruption_matches_within_epsilon() {
+        let mut ecs = SoaEcs::with_capacity(0);
+        let levels = [0.72, 0.1, 0.72 + 1e-12, 0.7, 0.72, 0.75];
+        let entities: Vec<Entity> = levels
+            .iter()
+            .map(|&corruption_level| {
+                let entity = ecs.spawn();
+                ecs.golden_blood
+                    .insert(entity, GoldenBlood { corruption_level });
+                entity
+            })
+            .collect();
+        // Despawning and reinserting shuffles dense order; results still come back sorted.
+        ecs.golden_blood.remove(entities[0]);
+        ecs.golden_blood.insert(
+            entities[0],
+            GoldenBlood {
+                corruption_level: 0.72,
+            },
+        );
+
+        let titans = [entities[0], entities[2], entities[4]];
+        assert_eq!(ecs.entities_with_corruption(0.72, 1e-9), titans);
+        assert_eq!(ecs.entities_with_corruption(0.72, -1e-9), titans);
+        assert_eq!(
+            ecs.entities_with_corruption(0.72, 0.0),
+            [entities[0], entities[4]]
+        );
+        assert_eq!(
+            ecs.entities_with_corruption(0.725, 0.03),
+            [
+                entities[0],
+                entities[2],
+                entities[3],
+                entities[4],
+                entities[5]
+            ]
+        );
+        assert!(ecs.entities_with_corruption(f64::NAN, 1.0).is_empty());
+        assert!(ecs.entities_with_corruption(0.72, f64::NAN).is_empty());
+    }
+
+    #[test]
+    fn power_gini_matches_hand_computed_coefficients() {
+        let world = |powers: &[f64]| {
+            let mut ecs = SoaEcs::with_capacity(0);
+            for &power_level in powers {
+                let entity = ecs.spawn();
+                ecs.coreflames.insert(
+                    entity,
+                    Coreflame {
+                        power_level,
+                        alignment: Path::Erudition,
+                    },
+                );
+            }
+            ecs
+        };
+
+        // Sorted 1, 2, 3, 4: 2·(1 + 4 + 9 + 16) / (4·10) − 5/4 = 0.25.
+        assert_eq!(world(&[3.0, 1.0, 4.0, 2.0]).power_gini(), 0.25);
+        // One titan holds everything: 2·(4·10) / (4·10) − 5/4 = 0.75.
+        assert_eq!(world(&[0.0, 10.0, 0.0, 0.0]).power_gini(), 0.75);
+        assert_eq!(world(&[1.5; 8]).power_gini(), 0.0);
+        assert_eq!(world(&[]).power_gini(), 0.0);
+        assert_eq!(world(&[7.0]).power_gini(), 0.0);
+        assert_eq!(world(&[0.0, 0.0]).power_gini(), 0.0);
+    }
+
+    #[test]
+    fn merge_from_respawns_entities_and_keeps_components_under_new_ids() {
+        let mut merged = mixed_world(300);
+        let existing = merged.snapshot();
+        let other = mixed_world(120).snapshot();
+
+        let remap = merged.merge_from(&other);
+        assert_eq!(merged.entity_count(), 300 + 120);
+        assert_eq!(remap.len(), 120);
+        assert!(remap.values().all(|&spawned| spawned >= 300));
+
+        for (entity, coreflame) in &other.coreflames {
+            assert_eq!(merged.coreflames.get(remap[entity]), Some(coreflame));
+        }
+        for (entity, memory_log) in &other.memory_logs {
+            assert_eq!(merged.memory_logs.get(remap[entity]), Some(memory_log));
+        }
+        for (entity, golden_blood) in &other.golden_blood {
+            assert_eq!(merged.golden_blood.get(remap[entity]), Some(golden_blood));
+        }
+        assert_eq!(
+            merged.coreflames.len(),
+            existing.coreflames.len() + other.coreflames.len()
+        );
+        assert_eq!(
+            merged.snapshot_entities(&existing.entities),
+            existing,
+            "merging leaves the original entities alone"
+        );
+    }
+
+    #[test]
+    fn sample_entities_is_reproducible_and_short_only_for_small_worlds() {
+        let mut ecs = mixed_world(2_000);
+        for entity in (0..2_000).step_by(3) {
+            assert!(ecs.despawn(entity));
+        }
+
+        let sample = ecs.sample_entities(50, 476);
+        assert_eq!(sample.len(), 50);
+        assert_eq!(ecs.sample_entities(50, 476), sample);
+        assert_ne!(ecs.sample_entities(50, 477), sample);
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(sample.iter().all(|&entity| ecs.is_alive(entity)));
+
+        let everyone = ecs.sample_entities(5_000, 476);
+        assert_eq!(everyone.len(), ecs.entity_count());
+        assert!(ecs.sample_entities(0, 476).is_empty());
+        assert!(SoaEcs::with_capacity(0).sample_entities(10, 476).is_empty());
+    }
+
+    #[test]
+    fn dominant_path_counts_alignments_and_breaks_ties_by_declaration_order() {
+        let world = |alignments: &[Path]| {
+            let mut ecs = SoaEcs::with_capacity(0);
+            for &alignment in alignments {
+                let entity = ecs.spawn();
+                ecs.coreflames.insert(
+                    entity,
+                    Coreflame {
+                        power_level: 1.0,
+                        alignment,
+                    },
+                );
+            }
+            ecs
+        };
+
+        let contested = world(&[
+            Path::Remembrance,
+            Path::Destruction,
+            Path::Destruction,
+            Path::Erudition,
+            Path::Destruction,
+            Path::Erudition,
+        ]);
+        assert_eq!(
+            contested.count_by_path(),
+            [
+                (Path::Erudition, 2),
+                (Path::Destruction, 3),
+                (Path::Remembrance, 1),
+                (Path::None, 0),
+            ]
+        );
+        assert_eq!(contested.dominant_path(), Path::Destruction);
+
+        let tied = [
+            Path::Remembrance,
+            Path::Destruction,
+            Path::Remembrance,
+            Path::Destruction,
+        ];
+        assert_eq!(world(&tied).dominant_path(), Path::Destruction);
+        assert_eq!(
+            world(&[Path::None, Path::Remembrance]).dominant_path(),
+            Path::Remembrance
+        );
+        assert_eq!(world(&[]).dominant_path(), Path::None);
+    }
+
+    #[test]
+    fn ascending_inserts_reallocate_the_sparse_array_logarithmically() {
+        let mut store = ComponentStore::with_capacity(0, 0);
+        let mut reallocations = 0;
+        let mut capacity = store.sparse.capacity();
+        for entity in 0..10_000 {
+            store.insert(entity, entity);
+            if store.sparse.capacity() != capacity {
+                capacity = store.sparse.capacity();
+                reallocations += 1;
+            }
+        }
+        // One growth per doubling up to 16_384 slots.
+        assert!(reallocations <= 15, "{reallocations} reallocations");
+        assert!(store.sparse.len() >= 10_000);
+        assert_eq!(store.get(9_999), Some(&9_999));
+    }
+
+    #[test]
+    fn check_invariants_accepts_a_churned_store_and_flags_corruption() {
+        let mut store = ComponentStore::with_capacity(16, 16);
+        for entity in 0..12 {
+            store.insert(entity, entity * 10);
+        }
+        for entity in [3, 0, 11, 7] {
+            store.remove(entity);
+        }
+        store.insert(3, 300);
+        assert_eq!(store.check_invariants(), Ok(()));
+
+        let mut dangling = ComponentStore::with_capacity(4, 4);
+        dangling.insert(1, 'a');
+        dangling.insert(2, 'b');
+        dangling.sparse[1] = 2;
+        assert_eq!(
+            dangling.check_invariants(),
+            Err(StoreError::DanglingSlot {
+                entity: 1,
+                dense_index: 1,
+            })
+        );
+
+        let mut unmapped = ComponentStore::with_capacity(4, 4);
+        unmapped.insert(2, 'b');
+        unmapped.sparse[2] = 0;
+        assert_eq!(
+            unmapped.check_invariants(),
+            Err(StoreError::BrokenBackPointer {
+                entity: 2,
+                dense_index: 0,
+            })
+        );
+
+        let mut ragged = ComponentStore::with_capacity(4, 4);
+        ragged.insert(0, 'a');
+        ragged.dense_data.push('z');
+        assert_eq!(
+            ragged.check_invariants(),
+            Err(StoreError::LengthMismatch {
+                entities: 1,
+                data: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn worlds_with_a_base_allocate_ids_from_it_across_black_tides() {
+        let mut ecs = SoaEcs::with_capacity_and_base(8, 1_000);
+        assert_eq!(ecs.id_base(), 1_000);
+        let first = ecs.spawn();
+        let second = ecs.spawn();
+        assert_eq!((first, second), (1_000, 1_001));
+        ecs.coreflames.insert(
+            second,
+            Coreflame {
+                power_level: 2.0,
+                alignment: Path::Remembrance,
+            },
+        );
+        assert!(ecs.is_alive(first) && !ecs.is_alive(0));
+        assert_eq!(ecs.coreflames.get(second).map(|c| c.power_level), Some(2.0));
+        assert_eq!(ecs.sample_entities(4, 9), vec![1_000, 1_001]);
+
+        ecs.clear_for_black_tide();
+        assert_eq!(ecs.spawn(), 1_000);
+        assert_eq!(ecs.entity_count(), 1);
+    }
+
+    #[test]
+    fn default_path_insertion_uses_the_configured_alignment() {
+        let mut ecs = SoaEcs::with_capacity(4);
+        let unset = ecs.spawn();
+        assert!(ecs.insert_coreflame_default(unset, 0.5));
+        assert_eq!(
+            ecs.coreflames.get(unset).map(|c| c.alignment),
+            Some(Path::None)
+        );
+
+        ecs.set_default_path(Path::Remembrance);
+        let scripted = ecs.spawn();
+        assert!(ecs.insert_coreflame_default(scripted, 2.0));
+        assert_eq!(
+            ecs.coreflames.get(scripted).copied(),
+            Some(Coreflame {
+                power_level: 2.0,
+                alignment: Path::Remembrance,
+            })
+        );
+        assert!(!ecs.insert_coreflame_default(99, 1.0));
+        assert!(ecs.despawn(scripted));
+        assert!(!ecs.insert_coreflame_default(scripted, 1.0));
+        assert!(ecs.coreflames.get(scripted).is_none());
+
+        ecs.clear_for_black_tide();
+        assert_eq!(ecs.default_path(), Path::Remembrance);
+    }
+
+    #[test]
+    fn despawned_ids_are_recycled_so_churn_does_not_grow_the_store() {
+        let mut ecs = SoaEcs::with_capacity(8);
+        let first: Vec<Entity> = (0..8).map(|_| ecs.spawn()).collect();
+        for _ in 0..10_000 {
+            let entity = ecs.spawn();
+            assert!(ecs.despawn(entity));
+        }
+        assert_eq!(ecs.spawned_total(), 10_008);
+        assert_eq!(ecs.entity_span(), 9);
+        assert_eq!(ecs.free_count(), 1);
+
+        assert!(ecs.despawn(first[2]) && ecs.despawn(first[5]));
+        assert_eq!(ecs.free_count(), 3);
+        assert_eq!(ecs.spawn(), first[5]);
+        assert_eq!(ecs.spawn(), first[2]);
+        assert_eq!(ecs.spawn(), 8);
+        assert_eq!((ecs.free_count(), ecs.spawn()), (0, 9));
+        assert_eq!(ecs.entity_count(), 10);
+
+        ecs.clear_for_black_tide();
+        assert_eq!((ecs.spawned_total(), ecs.free_count()), (0, 0));
+        assert_eq!(ecs.spawn(), 0);
+    }
+
+    #[test]
+    fn recent_spawns_keep_the_latest_ids_oldest_first() {
+        let mut ecs = SoaEcs::with_capacity(0);
+        let first: Vec<Entity> = (0..10).map(|_| ecs.spawn()).collect();
+        assert_eq!(ecs.recent_spawns(), first);
+
+        let spawned: Vec<Entity> = (0..RECENT_SPAWN_CAPACITY * 2 + 3)
+            .map(|_| ecs.spawn())
+            .collect();
+        assert_eq!(
+            ecs.recent_spawns(),
+            &spawned[spawned.len() - RECENT_SPAWN_CAPACITY..]
+        );
+
+        ecs.clear_for_black_tide();
+        assert!(ecs.recent_spawns().is_empty());
+        let after_tide = ecs.spawn();
+        assert_eq!(ecs.recent_spawns(), [after_tide]);
+    }
+
+    #[test]
+    fn stale_handles_stop_resolving_once_their_slot_is_reused() {
+        let mut ecs = SoaEcs::with_capacity(0);
+        let original = ecs.spawn();
+        let stale = ecs.handle(original).expect("just spawned");
+        assert_eq!(ecs.resolve(stale), Some(original));
+
+        assert!(ecs.despawn(original));
+        assert!(!ecs.is_handle_alive(stale));
+        let reused = ecs.spawn();
+        assert_eq!(reused, original);
+        let fresh = ecs.handle(reused).expect("just spawned");
+        assert_ne!(fresh, stale);
+        assert_eq!(ecs.resolve(stale), None);
+        assert_eq!(ecs.resolve(fresh), Some(reused));
+
+        ecs.clear_for_black_tide();
+        assert_eq!(ecs.spawn(), reused);
+        assert!(!ecs.is_handle_alive(fresh));
+        assert!(!ecs.is_handle_alive(stale));
+    }
+
+    /// Everything `get` and the ID bookkeeping report for every slot of `ecs`.
+    #[allow(clippy::type_complexity)]
+    fn slot_contents(
+        ecs: &SoaEcs,
+    ) -> Vec<(
+        Option<EntityHandle>,
+        Option<Coreflame>,
+        Option<MemoryLog>,
+        Option<GoldenBlood>,
+        Option<Cohort>,
+        Option<CorruptionResistance>,
+    )> {
+        (0..ecs.entity_span() as Entity)
+            .map(|entity| {
+                (
+                    ecs.handle(entity),
+                    ecs.coreflames.get(entity).copied(),
+                    ecs.memory_logs.get(entity).copied(),
+                    ecs.golden_blood.get(entity).copied(),
+                    ecs.cohorts.get(entity).copied(),
+                    ecs.corruption_resistance.get(entity).copied(),
+                )
+            })
+            .collect()
+    }
+
+    /// A mixed world with tags, despawns, and recycled slots.
+    fn churned_world(entities: u32) -> SoaEcs {
+        let mut ecs = SoaEcs::with_capacity(entities as usize);
+        let spawn = |ecs: &mut SoaEcs, i: u32| {
+            let entity = ecs.spawn_at_cycle(u64::from(i % 17));
+            let level = f64::from(i % 100) / 100.0;
+            if i.is_multiple_of(2) {
+                ecs.coreflames.insert(
+                    entity,
+                    Coreflame {
+                        power_level: level * 2.0,
+                        alignment: Path::ALL[i as usize % Path::ALL.len()],
+                    },
+                );
+            }
+            if i.is_multiple_of(3) {
+                ecs.memory_logs.insert(
+                    entity,
+                    MemoryLog {
+                        retained_cycles: u64::from(i),
+                        trauma_index: level,
+                    },
+                );
+            }
+            if !i.is_multiple_of(5) {
+                ecs.golden_blood.insert(
+                    entity,
+                    GoldenBlood {
+                        corruption_level: level,
+                    },
+                );
+            }
+            if i.is_multiple_of(4) {
+                ecs.cohorts.insert(entity, Cohort::Titan);
+            }
+            if i.is_multiple_of(11) {
+                ecs.corruption_resistance
+                    .insert(entity, CorruptionResistance { factor: level });
+            }
+            entity
+        };
+        let spawned: Vec<Entity> = (0..entities).map(|i| spawn(&mut ecs, i)).collect();
+        for &entity in spawned.iter().step_by(4) {
+            ecs.despawn(entity);
+        }
+        // Refill some of the freed slots so recycled generations are part of the world.
+        for i in 0..entities / 8 {
+            spawn(&mut ecs, entities + i);
+        }
+        ecs
+    }
+
+    #[test]
+    fn soa_ecs_serde_round_trip_preserves_every_slot() {
+        let mut ecs = churned_world(4000);
+        let expected = slot_contents(&ecs);
+        let expected_ids = (
+            ecs.entity_count(),
+            ecs.spawned_total(),
+            ecs.free_count(),
+            ecs.recent_spawns().to_vec(),
+        );
+        assert!(
+            expected
+                .iter()
+                .any(|slot| slot.0.is_some_and(|h| h.generation > 0))
+        );
+
+        let bytes = encode_to_vec(&ecs, standard()).expect("world encodes");
+        ecs.clear_for_black_tide();
+        let (decoded, _): (SoaEcs, usize) =
+            decode_from_slice(&bytes, standard()).expect("world decodes");
+
+        assert_eq!(slot_contents(&decoded), expected);
+        assert_eq!(
+            (
+                decoded.entity_count(),
+                decoded.spawned_total(),
+                decoded.free_count(),
+                decoded.recent_spawns().to_vec(),
+            ),
+            expected_ids
+        );
+        assert_eq!(decoded.coreflames.check_invariants(), Ok(()));
+        assert_eq!(decoded.golden_blood.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn decoded_world_allocates_ids_like_the_original() {
+        let mut original = churned_world(600);
+        let bytes = encode_to_vec(&original, standard()).expect("world encodes");
+        let (mut decoded, _): (SoaEcs, usize) =
+            decode_from_slice(&bytes, standard()).expect("world decodes");
+        for _ in 0..200 {
+            let entity = original.spawn();
+            assert_eq!(decoded.spawn(), entity);
+            assert_eq!(decoded.handle(entity), original.handle(entity));
+        }
+    }
+
+    #[test]
+    fn panicked_writes_reset_or_refuse_as_configured() {
+        let world = EcsWorld::new(0);
+        let panicking_write = || {
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                world.write(|ecs| {
+                    let entity = ecs.spawn();
+                    ecs.golden_blood.insert(
+                        entity,
+                        GoldenBlood {
+                            corruption_level: 0.5,
+                        },
+                    );
+                    panic!("injected mid-mutation panic");
+                })
+            }));
+            assert!(result.is_err());
+        };
+        let seed_two = || {
+            world.write(|ecs| {
+                ecs.spawn();
+                ecs.spawn();
+            })
+        };
+
+        seed_two().expect("a fresh world accepts writes");
+        panicking_write();
+        assert_eq!(
+            world.read(|ecs| (ecs.entity_count(), ecs.golden_blood.len())),
+            (0, 0)
+        );
+        assert!(!world.read(|ecs| ecs.is_mutation_interrupted()));
+        seed_two().expect("a reset world accepts writes");
+
+        let _ = world.write(|ecs| ecs.set_poison_recovery(PoisonRecovery::Refuse));
+        panicking_write();
+        assert_eq!(seed_two(), None);
+        assert_eq!(
+            world.read(|ecs| (ecs.is_mutation_interrupted(), ecs.entity_count())),
+            (true, 3)
+        );
+        world.reset();
+        assert_eq!(world.write(|ecs| ecs.entity_count()), Some(0));
+    }
+
+    #[test]
+    fn coreflame_golden_join_visits_exactly_the_entities_with_both() {
+        let mut ecs = SoaEcs::with_capacity(16);
+        for i in 0..12_u32 {
+            let entity = ecs.spawn();
+            if i % 2 == 0 {
+                ecs.coreflames.insert(
+                    entity,
+                    Coreflame {
+                        power_level: f64::from(i),
+                        alignment: Path::Erudition,
+                    },
+                );
+            }
+            if i % 3 == 0 {
+                ecs.golden_blood.insert(
+                    entity,
+                    GoldenBlood {
+                        corruption_level: f64::from(i) / 10.0,
+                    },
+                );
+            }
+        }
+
+        let joined: Vec<(Entity, f64, f64)> = ecs
+            .query_coreflame_golden()
+            .map(|(entity, flame, blood)| (entity, flame.power_level, blood.corruption_level))
+            .collect();
+        assert_eq!(joined, [(0, 0.0, 0.0), (6, 6.0, 0.6)]);
+
+        for (_, flame, blood) in ecs.query_coreflame_golden_mut() {
+            flame.power_level += 1.0;
+            blood.corruption_level = 1.0;
+        }
+        let after: Vec<(Entity, f64, f64)> = ecs
+            .query_coreflame_golden()
+            .map(|(entity, flame, blood)| (entity, flame.power_level, blood.corruption_level))
+            .collect();
+        assert_eq!(after, [(0, 1.0, 1.0), (6, 7.0, 1.0)]);
+        assert_eq!(
+            ecs.golden_blood.get(3).map(|blood| blood.corruption_level),
+            Some(0.3)
+        );
+    }
+
+    /// The get/insert/remove/iter contract every `ComponentStorage` backend must meet,
+    /// checked against a `HashMap` model over a random mix of operations.
+    fn check_storage_conformance<S: ComponentStorage<u64> + Default>() {
+        let mut store = S::default();
+        assert!(store.is_empty());
+        assert_eq!(store.get(7), None);
+        assert_eq!(store.remove(7), None);
+
+        store.insert(7, 70);
+        store.insert(7, 71);
+        assert_eq!((store.len(), store.get(7)), (1, Some(&71)));
+        *store.get_mut(7).expect("inserted") += 1;
+        assert_eq!(store.remove(7), Some(72));
+        assert!(!store.contains(7));
+
+        let mut model = HashMap::new();
+        let mut rng = SimRng::new(511);
+        for step in 0..5_000 {
+            let entity = rng.next_below(300) as Entity;
+            match rng.next_below(4) {
+                0 | 1 => {
+                    store.insert(entity, step);
+                    model.insert(entity, step);
+                }
+                2 => assert_eq!(store.remove(entity), model.remove(&entity)),
+                _ => {
+                    if let Some(value) = store.get_mut(entity) {
+                        *value += 1;
+                    }
+                    if let Some(value) = model.get_mut(&entity) {
+                        *value += 1;
+                    }
+                }
+            }
+            assert_eq!(store.get(entity), model.get(&entity));
+            assert_eq!(store.contains(entity), model.contains_key(&entity));
+            assert_eq!(store.len(), model.len());
+        }
+
+        for (_, value) in store.iter_mut() {
+            *value *= 2;
+        }
+        let mut contents: Vec<(Entity, u64)> = store
+            .iter()
+            .map(|(entity, &value)| (entity, value))
+            .collect();
+        contents.sort_unstable();
+        let mut expected: Vec<(Entity, u64)> = model
+            .iter()
+            .map(|(&entity, &value)| (entity, value * 2))
+            .collect();
+        expected.sort_unstable();
+        assert_eq!(contents, expected);
+
+        store.clear();
+        assert!(store.is_empty());
+        assert_eq!(store.iter().count(), 0);
+    }
+
+    #[test]
+    fn dense_store_meets_the_storage_contract() {
+        check_storage_conformance::<ComponentStore<u64>>();
+    }
+
+    #[test]
+    fn hash_store_meets_the_storage_contract() {
+        check_storage_conformance::<HashComponentStore<u64>>();
+    }
+
+    #[test]
+    fn clear_except_keeps_three_of_ten_thousand_intact() {
+        let mut ecs = mixed_world(10_000);
+        for entity in (0..10_000).step_by(7) {
+            ecs.cohorts.insert(entity, Cohort::ChrysosHeir);
+            ecs.corruption_resistance
+                .insert(entity, CorruptionResistance { factor: 0.5 });
+        }
+        let keep = [0, 4_242, 9_999];
+        let before = slot_contents(&ecs);
+        assert!(keep.iter().all(|&entity| ecs.is_alive(entity)));
+
+        ecs.clear_except(&keep);
+        let after = slot_contents(&ecs);
+        for (entity, (was, now)) in before.iter().zip(&after).enumerate() {
+            if keep.contains(&(entity as Entity)) {
+                assert_eq!(now, was);
+            } else {
+                assert_eq!(now, &(None, None, None, None, None, None));
+            }
+        }
+        assert_eq!(ecs.entity_count(), keep.len());
+
+        let dense_kept = |entities: &[Entity]| entities.iter().all(|entity| keep.contains(entity));
+        assert!(dense_kept(ecs.coreflames.dense_entities()));
+        assert!(dense_kept(ecs.memory_logs.dense_entities()));
+        assert!(dense_kept(ecs.golden_blood.dense_entities()));
+        assert!(dense_kept(ecs.cohorts.dense_entities()));
+        assert!(
+            ecs.corruption_resistance
+                .iter()
+                .all(|(entity, _)| keep.contains(&entity))
+        );
+        assert_eq!(ecs.corruption_resistance.len(), 2);
+        assert_eq!(ecs.coreflames.check_invariants(), Ok(()));
+        assert_eq!(ecs.golden_blood.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn contains_is_false_beyond_the_sparse_array() {
+        let mut store = ComponentStore::with_capacity(4, 4);
+        store.insert(2, 1.5_f64);
+        assert!(store.contains(2));
+        assert!(!store.contains(3));
+        assert!(!store.contains(1_000_000));
+        assert!(!store.contains(Entity::MAX));
+        assert!(store.remove(2).is_some());
+        assert!(!store.contains(2));
+
+        let mut ecs = SoaEcs::with_capacity(0);
+        let entity = ecs.spawn();
+        assert!(ecs.insert_coreflame_default(entity, 1.0));
+        ecs.golden_blood.insert(entity, GoldenBlood::default());
+        assert!(ecs.has_coreflame(entity) && ecs.has_golden_blood(entity));
+        assert!(!ecs.has_memory_log(entity));
+        for beyond in [entity + 1, 1 << 20, Entity::MAX] {
+            assert!(!ecs.has_coreflame(beyond));
+            assert!(!ecs.has_memory_log(beyond));
+            assert!(!ecs.has_golden_blood(beyond));
+        }
+    }
 }
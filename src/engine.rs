@@ -1,5 +1,7 @@
 use std::fs;
 use std::mem::{align_of, size_of};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use bincode::config::standard;
 use bincode::serde::encode_to_vec;
@@ -9,9 +11,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::arena::AmphoreusArena;
 use crate::ecs::{
-    Coreflame, Entity, GoldenBlood, MemoryLog, Path, with_global_ecs, with_global_ecs_mut,
+    Cohort, CohortStats, ComponentStorage, Coreflame, CorruptionResistance, EcsSnapshot, EcsWorld,
+    Entity, EntityHandle, Faction, GoldenBlood, HashComponentStore, Lifespan, MemoryLog, Path,
+    SoaEcs, WatchMask, global_world,
 };
-use crate::equation::{DestructionNode, evaluate_destruction_ast};
+use crate::equation::{
+    DestructionNode, EvalConfig, evaluate_destruction_ast, evaluate_destruction_ast_with,
+};
+use crate::page::{self, EternalPage, PageCompression, RestoreError};
+use crate::rng::SimRng;
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct GlobalState {
@@ -37,11 +45,17 @@ pub enum SimulationResult {
     BlackTideTriggered,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WorldSeedConfig {
     pub citizens: u32,
     pub titans: u32,
     pub chrysos_heirs: u32,
+    /// Whether `seed_world` hands Phainon the memory carried from earlier worlds; when false
+    /// he starts from a fresh memory log. Black tides always carry it forward.
+    pub keep_phainon_memory: bool,
+    /// Seed of the generator population seeding draws from; the same seed and counts give an
+    /// identical world.
+    pub rng_seed: u64,
 }
 
 impl Default for WorldSeedConfig {
@@ -50,100 +64,958 @@ impl Default for WorldSeedConfig {
             citizens: 12_000,
             titans: 320,
             chrysos_heirs: 64,
+            keep_phainon_memory: true,
+            rng_seed: 0,
+        }
+    }
+}
+
+/// Entropy gained per unit increase of each destruction AST input; see
+/// `AmphoreusEngine::entropy_sensitivities`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntropySensitivity {
+    /// Per additional entity.
+    pub d_entity_count: f64,
+    /// Per unit of average golden-blood corruption.
+    pub d_corruption: f64,
+    /// Per unit of Phainon's trauma index.
+    pub d_trauma: f64,
+}
+
+/// Finite-difference step `entropy_sensitivities` uses for corruption and trauma.
+pub const ENTROPY_SENSITIVITY_STEP: f64 = 1e-6;
+
+/// Outcome of a `seed_world` call; `spawned < requested` when seeding was cancelled or a
+/// spawn failed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SeedReport {
+    pub spawned: u32,
+    pub requested: u32,
+    pub cancelled: bool,
+}
+
+/// Why `AmphoreusEngine::spawn_entity` created no entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnError {
+    /// The arena has no room left for the entity's components.
+    ArenaExhausted,
+    /// The world refuses writes after a panicked mutation under `PoisonRecovery::Refuse`.
+    EcsUnavailable,
+}
+
+impl std::fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ArenaExhausted => write!(f, "the arena has no room for another entity"),
+            Self::EcsUnavailable => write!(f, "the world is refusing writes"),
         }
     }
 }
 
+impl std::error::Error for SpawnError {}
+
+/// A `seed_world` call during which at least one spawn failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedError {
+    /// What was spawned anyway; seeding carries on past failed spawns.
+    pub report: SeedReport,
+    /// The first failed spawn.
+    pub cause: SpawnError,
+}
+
+impl std::fmt::Display for SeedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "seeded {} of {} entities: {}",
+            self.report.spawned, self.report.requested, self.cause
+        )
+    }
+}
+
+impl std::error::Error for SeedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.cause)
+    }
+}
+
+/// How many spawns happen between checks of the seed cancellation token.
+const SEED_CANCEL_CHECK_INTERVAL: u32 = 1024;
+
+/// Progression metadata written by `AmphoreusEngine::save_state_only`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StateCheckpoint {
+    pub state: GlobalState,
+    pub config: EngineConfig,
+    /// Black tides in the engine's ledger, which `black_tide_history_limit` bounds.
+    pub black_tide_count: usize,
+}
+
+/// Generational handles to Phainon and Cyrene, so neither resolves to whoever reuses their
+/// slots after they die.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlameChaseHandles {
-    pub phainon: Option<Entity>,
-    pub cyrene: Option<Entity>,
+    pub phainon: Option<EntityHandle>,
+    pub cyrene: Option<EntityHandle>,
+}
+
+/// A memory log the engine carries across black tides for one character; see
+/// `AmphoreusEngine::register_persistent_memory`. Phainon's is always the first.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PersistentMemory {
+    /// The entity carrying the memory now; `None` until one has been spawned for it.
+    pub handle: Option<EntityHandle>,
+    pub log: MemoryLog,
+    /// Components a reseed respawns the character with; `log` stands in for its memory log.
+    pub respawn: SpawnEntitySpec,
+}
+
+impl PersistentMemory {
+    fn spawn_spec(&self) -> SpawnEntitySpec {
+        SpawnEntitySpec {
+            memory_log: Some(self.log),
+            ..self.respawn
+        }
+    }
+}
+
+/// Index of Phainon's entry in the engine's persistent memories.
+const PHAINON_MEMORY: usize = 0;
+
+/// Which way entropy moved through a watermark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrossingDirection {
+    Upward,
+    Downward,
+}
+
+/// A watermark that entropy passed during one tick of `run_fixed`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntropyCrossing {
+    /// Index of the tick within the run, starting at 0.
+    pub tick: u64,
+    pub threshold: f64,
+    pub direction: CrossingDirection,
+}
+
+/// Appends to `log` every watermark entropy passed moving from `previous` to `current`.
+///
+/// Rising entropy crosses `w` when `previous < w <= current` and falling entropy when
+/// `current < w <= previous`. Several watermarks passed in one step are logged in the
+/// order entropy reached them; NaN watermarks are never crossed.
+pub fn record_entropy_crossings(
+    log: &mut Vec<EntropyCrossing>,
+    tick: u64,
+    previous: f64,
+    current: f64,
+    watermarks: &[f64],
+) {
+    let start = log.len();
+    let direction = if current > previous {
+        CrossingDirection::Upward
+    } else {
+        CrossingDirection::Downward
+    };
+    log.extend(
+        watermarks
+            .iter()
+            .copied()
+            .filter(|&threshold| match direction {
+                CrossingDirection::Upward => previous < threshold && threshold <= current,
+                CrossingDirection::Downward => current < threshold && threshold <= previous,
+            })
+            .map(|threshold| EntropyCrossing {
+                tick,
+                threshold,
+                direction,
+            }),
+    );
+
+    let crossed = &mut log[start..];
+    match direction {
+        CrossingDirection::Upward => crossed.sort_by(|a, b| a.threshold.total_cmp(&b.threshold)),
+        CrossingDirection::Downward => crossed.sort_by(|a, b| b.threshold.total_cmp(&a.threshold)),
+    }
+}
+
+/// Ledger entry describing the world at the moment a black tide fired.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BlackTideRecord {
+    /// Cycle count at collapse, before the tide advanced it.
+    pub cycle: u64,
+    pub entropy: f64,
+    pub entity_count: usize,
+    pub average_corruption: f64,
+    /// Per-cohort death report, taken just before the world was wiped.
+    pub cohorts: CohortReport,
+}
+
+/// How each seeded cohort stood at the moment of a black tide.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CohortReport {
+    pub citizens: CohortStats,
+    pub titans: CohortStats,
+    pub chrysos_heirs: CohortStats,
+}
+
+/// Read-only view of the flame-chase pair for observers.
+///
+/// Fields are `None` when the corresponding entity failed to spawn or lost the component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlameChaseSnapshot {
+    pub phainon_trauma: Option<f64>,
+    pub phainon_cycles: Option<u64>,
+    pub cyrene_trauma: Option<f64>,
+    pub exploit_active: bool,
+}
+
+/// What happens to entities whose coreflame has been corrupted down to zero power.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ZeroPowerPolicy {
+    /// Leave powerless entities in the world untouched.
+    #[default]
+    Keep,
+    /// Cull powerless entities from the world.
+    Despawn,
+    /// Strip the alignment of powerless entities back to `Path::None`.
+    Revert,
+}
+
+/// Weights of the signals `AmphoreusEngine::collapse_momentum` combines; negative weights
+/// count as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MomentumWeights {
+    pub entropy_rate: f64,
+    pub corruption_velocity: f64,
+    pub population_rate: f64,
+}
+
+impl Default for MomentumWeights {
+    fn default() -> Self {
+        Self {
+            entropy_rate: 0.5,
+            corruption_velocity: 0.35,
+            population_rate: 0.15,
+        }
+    }
+}
+
+/// Entropy gained per tick at which the entropy-rate signal reaches `tanh(1)`.
+pub const MOMENTUM_ENTROPY_RATE_SCALE: f64 = 0.01;
+/// Average corruption gained per tick at which the corruption signal reaches `tanh(1)`.
+pub const MOMENTUM_CORRUPTION_VELOCITY_SCALE: f64 = 0.005;
+/// Relative population growth per tick at which the population signal reaches `tanh(1)`.
+pub const MOMENTUM_POPULATION_RATE_SCALE: f64 = 0.01;
+
+/// How the entity count feeds the destruction AST.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntityScaling {
+    /// `DestructionNode::EntityCount`, saturating at one million entities.
+    #[default]
+    Linear,
+    /// `DestructionNode::LogEntityCount`, which never saturates but weighs small worlds more.
+    Logarithmic,
+}
+
+/// When a tick's destruction entropy collapses the world into a black tide.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BlackTideTrigger {
+    /// A tide fires once entropy reaches this value.
+    Threshold(f64),
+    /// A tide fires with chance `entropy.powf(curve)` each tick, drawn from the engine rng.
+    ///
+    /// `curve` 1.0 is linear; larger values hold collapse back until entropy is near 1.0,
+    /// where a tide is certain. Values below `f64::EPSILON` count as `f64::EPSILON`.
+    Probabilistic { curve: f64 },
+}
+
+impl Default for BlackTideTrigger {
+    fn default() -> Self {
+        Self::Threshold(1.0)
+    }
+}
+
+impl BlackTideTrigger {
+    /// Whether a tide fires at `entropy`; only the probabilistic trigger draws from `rng`.
+    fn fires(self, entropy: f64, rng: &mut SimRng) -> bool {
+        match self {
+            Self::Threshold(threshold) => entropy >= threshold,
+            Self::Probabilistic { curve } => {
+                let chance = entropy.clamp(0.0, 1.0).powf(curve.max(f64::EPSILON));
+                rng.next_f64() < chance
+            }
+        }
+    }
+}
+
+/// How many of `world_seed`'s entities a black tide reseeds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum ReseedPolicy {
+    /// Reseed the exact `world_seed` counts.
+    #[default]
+    Exact,
+    /// Scale every count by `1.0 - entropy` of the collapse, but never below `min_fraction`
+    /// (clamped to [0.0, 1.0]), so harsher collapses leave a diminished world.
+    ///
+    /// Counts always scale from `world_seed`, not from the previous reseed. Under
+    /// `BlackTideTrigger::Threshold(1.0)` every collapse reseeds at `min_fraction`.
+    ScaleBySeverity { min_fraction: f64 },
+}
+
+impl ReseedPolicy {
+    /// `seed` with its counts scaled for a collapse at `entropy`.
+    fn scaled(self, seed: WorldSeedConfig, entropy: f64) -> WorldSeedConfig {
+        let Self::ScaleBySeverity { min_fraction } = self else {
+            return seed;
+        };
+        let fraction = (1.0 - entropy.clamp(0.0, 1.0)).max(min_fraction.clamp(0.0, 1.0));
+        let scale = |count: u32| (f64::from(count) * fraction).round() as u32;
+        WorldSeedConfig {
+            citizens: scale(seed.citizens),
+            titans: scale(seed.titans),
+            chrysos_heirs: scale(seed.chrysos_heirs),
+            ..seed
+        }
+    }
+}
+
+/// What the corruption pass does when a decay step would take a coreflame's power below zero.
+///
+/// Each step multiplies power by `1 - corruption * power_decay_rate`, so this only happens
+/// once `EngineConfig::power_decay_rate` lets the factor turn negative, or for power that was
+/// already negative.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClampPolicy {
+    /// Clamp power at 0.0.
+    #[default]
+    Saturate,
+    /// Leave power as it was when the decayed value would be negative, and flag the entity.
+    Error,
+    /// Clamp power at 0.0, flag the entity, and despawn it once the pass has finished.
+    ///
+    /// Unlike `ZeroPowerPolicy::Despawn`, coreflames that merely sit at zero are kept.
+    Despawn,
+}
+
+impl ClampPolicy {
+    /// One decay step of `power`; returns the new power and whether the entity is flagged.
+    fn decay(self, power: f64, corruption_level: f64, decay_rate: f64) -> (f64, bool) {
+        let decayed = power * (1.0 - corruption_level * decay_rate);
+        match self {
+            Self::Saturate => (decayed.max(0.0), false),
+            Self::Error if decayed < 0.0 => (power, true),
+            Self::Error => (decayed, false),
+            Self::Despawn => (decayed.max(0.0), decayed < 0.0),
+        }
+    }
+}
+
+/// Which passes still run on a tick where Cyrene's exploit has stopped time.
+///
+/// `TimeExploit` always runs, since it decides whether time is stopped at all. Passes
+/// scheduled before it in `pass_order` have already run by the time a bypass is detected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeBypassPolicy {
+    /// Every pass keeps running; only `cycle_count` stands still.
+    #[default]
+    Current,
+    /// Entropy is still evaluated, but no component state changes.
+    EntropyOnly,
+    /// Nothing runs: components and entropy stay exactly as they were.
+    FreezeAll,
+}
+
+impl TimeBypassPolicy {
+    fn runs(self, pass: EnginePass) -> bool {
+        match self {
+            Self::Current => true,
+            Self::EntropyOnly => matches!(pass, EnginePass::TimeExploit | EnginePass::Entropy),
+            Self::FreezeAll => pass == EnginePass::TimeExploit,
+        }
+    }
+}
+
+/// Order in which entities are visited by the corruption pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CorruptionOrder {
+    /// Dense storage order, processed in parallel; depends on spawn/despawn history.
+    #[default]
+    Storage,
+    /// A seeded shuffle keyed on entity IDs, processed serially; independent of storage order.
+    Shuffled { seed: u64 },
+}
+
+impl CorruptionOrder {
+    /// Arranges `entities` into the order this strategy visits them.
+    pub fn arrange(self, entities: &[Entity]) -> Vec<Entity> {
+        let mut ordered = entities.to_vec();
+        if let Self::Shuffled { seed } = self {
+            ordered.sort_unstable_by_key(|&entity| (SimRng::hash(seed, entity as u64), entity));
+        }
+        ordered
+    }
+}
+
+/// One stage of `AmphoreusEngine::tick`; `EngineConfig::pass_order` sets their sequence.
+///
+/// The default order evaluates entropy before corruption, so corruption gains use this
+/// tick's entropy. Running `Corruption` before `Entropy` instead feeds corruption the
+/// previous tick's entropy and lets this tick's entropy see the freshly raised corruption.
+/// A fresh engine starts at zero entropy, so both orders then trace the same entropy;
+/// switched mid-run, the reordered engine runs a corruption step ahead and climbs faster
+/// toward a black tide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EnginePass {
+    /// Re-evaluates Cyrene's time exploit.
+    TimeExploit,
+    /// Evaluates the destruction AST into `destruction_entropy`.
+    Entropy,
+    /// Advances every persistent memory, Phainon's first.
+    PhainonMemory,
+    /// Spreads golden-blood corruption and applies the zero-power policy.
+    Corruption,
+    /// Counts another survived tick in every memory log except the persistent ones, and
+    /// despawns entities whose lifespan runs out.
+    MemoryAging,
+}
+
+impl EnginePass {
+    pub const DEFAULT_ORDER: [Self; 5] = [
+        Self::TimeExploit,
+        Self::Entropy,
+        Self::PhainonMemory,
+        Self::Corruption,
+        Self::MemoryAging,
+    ];
+}
+
+/// Why an `EngineConfig` was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    /// `pass_order` never schedules this pass.
+    MissingPass(EnginePass),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPass(pass) => write!(f, "pass order is missing the {pass:?} pass"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Disagreement between the arena and the world found by
+/// `AmphoreusEngine::audit_arena_consistency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// The arena offset falls outside the byte range the world's spawns account for.
+    ArenaDrift {
+        arena_offset: usize,
+        expected_min: usize,
+        expected_max: usize,
+    },
+}
+
+impl std::fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ArenaDrift {
+                arena_offset,
+                expected_min,
+                expected_max,
+            } => write!(
+                f,
+                "arena offset {arena_offset} is outside the expected {expected_min}..={expected_max} bytes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConsistencyError {}
+
+/// Tunable engine behavior, fixed at construction or swapped via `set_config`.
+///
+/// The whole config is written into every eternal page so a restored engine keeps its tuning.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EngineConfig {
+    /// Size of a dedicated rayon pool for the parallel passes; `None` uses the global pool.
+    pub worker_threads: Option<usize>,
+    pub zero_power_policy: ZeroPowerPolicy,
+    pub power_clamp_policy: ClampPolicy,
+    pub entity_scaling: EntityScaling,
+    pub time_bypass_policy: TimeBypassPolicy,
+    pub black_tide_trigger: BlackTideTrigger,
+    pub reseed_policy: ReseedPolicy,
+    /// Let living Chrysos heirs outlast black tides with their components and handles;
+    /// the reseed then only tops heirs back up to the `reseed_policy` count.
+    pub preserve_chrysos_heirs: bool,
+    pub corruption_order: CorruptionOrder,
+    /// When false the corruption pass is skipped: no blood gain and no alignment flips, while
+    /// entropy keeps being evaluated from the frozen averages.
+    pub corruption_enabled: bool,
+    /// Golden-blood corruption at or above which an entity spreads corruption and flips.
+    pub corruption_spread_threshold: f64,
+    /// Corruption each spreading entity gains per tick, per unit of destruction entropy.
+    pub entropy_to_corruption: f64,
+    /// Corruption resistance factor given to seeded Chrysos heirs; citizens corrupt at 1.0.
+    pub heir_corruption_resistance: f64,
+    /// Share of coreflame power lost per tick, per unit of corruption; above 1.0 a decay
+    /// step can overshoot zero, which `power_clamp_policy` then handles.
+    pub power_decay_rate: f64,
+    /// Weight of average corruption in the destruction AST's entropy multiplier.
+    pub corruption_to_entropy: f64,
+    /// Most Phainon's retained cycles can add to the memory multiplier; 0 disables the term.
+    pub retained_cycle_weight: f64,
+    /// Retained cycles at which that term reaches half of `retained_cycle_weight`; at least 1.
+    pub retained_cycle_saturation: f64,
+    pub page_compression: PageCompression,
+    /// Seed of the engine-owned `SimRng` that stateful random passes draw from in tick order.
+    ///
+    /// `CorruptionOrder::Shuffled` is a stateless keyed ordering and keeps its own seed.
+    pub master_seed: u64,
+    /// Keep arena room for Phainon and Cyrene while seeding the population, so a tight
+    /// arena drops citizens rather than the flame-chase pair.
+    pub reserve_flame_chase: bool,
+    /// Arena allocation granularity in bytes, raised to a power of two; every entity
+    /// allocation is rounded up to a multiple of it.
+    pub arena_granularity: usize,
+    /// Most recent black tides kept in the engine's ledger; older records are dropped.
+    pub black_tide_history_limit: usize,
+    /// Number of autosave files black tides rotate through, so the last few collapses stay
+    /// recoverable; values below 1 keep a single file.
+    pub autosave_ring_size: usize,
+    pub eval: EvalConfig,
+    pub momentum_weights: MomentumWeights,
+    /// Order in which `tick` runs its passes; every pass must appear.
+    pub pass_order: [EnginePass; 5],
+}
+
+impl EngineConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for pass in EnginePass::DEFAULT_ORDER {
+            if !self.pass_order.contains(&pass) {
+                return Err(ConfigError::MissingPass(pass));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            worker_threads: None,
+            zero_power_policy: ZeroPowerPolicy::default(),
+            power_clamp_policy: ClampPolicy::default(),
+            entity_scaling: EntityScaling::default(),
+            time_bypass_policy: TimeBypassPolicy::default(),
+            black_tide_trigger: BlackTideTrigger::default(),
+            reseed_policy: ReseedPolicy::default(),
+            preserve_chrysos_heirs: false,
+            corruption_order: CorruptionOrder::default(),
+            corruption_enabled: true,
+            corruption_spread_threshold: 0.6,
+            entropy_to_corruption: 0.05,
+            heir_corruption_resistance: 0.5,
+            power_decay_rate: 0.03,
+            corruption_to_entropy: 0.35,
+            retained_cycle_weight: 0.0,
+            retained_cycle_saturation: 100.0,
+            page_compression: PageCompression::default(),
+            master_seed: 0,
+            reserve_flame_chase: true,
+            arena_granularity: 1,
+            black_tide_history_limit: 256,
+            autosave_ring_size: 1,
+            eval: EvalConfig::default(),
+            momentum_weights: MomentumWeights::default(),
+            pass_order: EnginePass::DEFAULT_ORDER,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub struct SpawnEntitySpec {
     pub coreflame: Option<Coreflame>,
     pub memory_log: Option<MemoryLog>,
     pub golden_blood: Option<GoldenBlood>,
+    /// Cohort tag; bookkeeping only, so it takes no arena space.
+    pub cohort: Option<Cohort>,
+    /// Like the cohort tag, takes no arena space.
+    pub corruption_resistance: Option<CorruptionResistance>,
+    /// Faction tag; also takes no arena space.
+    pub faction: Option<Faction>,
+    /// Like the tags, takes no arena space.
+    pub lifespan: Option<Lifespan>,
+}
+
+impl SpawnEntitySpec {
+    /// The arena-allocated components `entity` carries now; tags that take no arena space
+    /// are left out.
+    fn arena_footprint(ecs: &SoaEcs, entity: Entity) -> Self {
+        Self {
+            coreflame: ecs.coreflames.get(entity).copied(),
+            memory_log: ecs.memory_logs.get(entity).copied(),
+            golden_blood: ecs.golden_blood.get(entity).copied(),
+            ..Self::default()
+        }
+    }
+
+    /// Arena bytes `spawn_entity` allocates for this spec, before alignment padding.
+    pub fn arena_bytes(&self) -> usize {
+        let bytes = size_of::<Entity>()
+            + self
+                .coreflame
+                .map(|_| size_of::<Coreflame>())
+                .unwrap_or_default()
+            + self
+                .memory_log
+                .map(|_| size_of::<MemoryLog>())
+                .unwrap_or_default()
+            + self
+                .golden_blood
+                .map(|_| size_of::<GoldenBlood>())
+                .unwrap_or_default();
+        bytes.max(1)
+    }
+
+    /// Arena bytes consumed in `arena`, including rounding and worst-case alignment padding.
+    fn padded_arena_bytes(&self, arena: &AmphoreusArena) -> usize {
+        arena
+            .rounded_len(self.arena_bytes())
+            .next_multiple_of(ENTITY_ALIGN.max(arena.granularity()))
+    }
+}
+
+/// Where a new engine autosaves: the working directory, or nowhere under `cargo test`.
+const DEFAULT_AUTOSAVE_PREFIX: Option<&str> = if cfg!(test) {
+    None
+} else {
+    Some("amphoreus_autosave")
+};
+
+/// Autosave file for ring position `slot`, e.g. `amphoreus_autosave.0.page`.
+fn autosave_path(prefix: &std::path::Path, slot: usize) -> PathBuf {
+    let mut path = prefix.as_os_str().to_owned();
+    path.push(format!(".{slot}.page"));
+    path.into()
 }
 
+/// Alignment of every per-entity arena allocation.
+const ENTITY_ALIGN: usize = align_of::<u64>();
+
 pub struct AmphoreusEngine {
     pub arena: AmphoreusArena,
     pub state: GlobalState,
     pub flame_chase: FlameChaseHandles,
     pub world_seed: WorldSeedConfig,
-    persistent_phainon_memory: MemoryLog,
-}
-
-#[derive(Serialize)]
-struct ArenaSnapshot<'a> {
-    offset: usize,
-    memory: &'a [u8],
+    world: EcsWorld,
+    config: EngineConfig,
+    #[cfg(not(target_arch = "wasm32"))]
+    worker_pool: Option<rayon::ThreadPool>,
+    persistent_memories: Vec<PersistentMemory>,
+    black_tides: Vec<BlackTideRecord>,
+    pending_perturbation: f64,
+    flips_last_tick: u32,
+    failed_spawns: u64,
+    clamped_last_tick: Vec<Entity>,
+    autosave_prefix: Option<PathBuf>,
+    /// Entities the aging pass found out of lifespan, despawned as soon as it finishes; empty
+    /// between ticks, so none of them outlive a black tide.
+    pending_despawns: Vec<Entity>,
+    last_average_corruption: f64,
+    corruption_velocity: f64,
+    last_entropy: f64,
+    entropy_rate: f64,
+    last_entity_count: usize,
+    population_rate: f64,
+    rng: SimRng,
 }
 
 impl AmphoreusEngine {
     pub fn new(arena_capacity: usize) -> Self {
-        Self {
-            arena: AmphoreusArena::new(arena_capacity),
+        Self::with_config(arena_capacity, EngineConfig::default())
+            .expect("default engine config is valid")
+    }
+
+    pub fn with_config(arena_capacity: usize, config: EngineConfig) -> Result<Self, ConfigError> {
+        Self::with_world(arena_capacity, config, global_world())
+    }
+
+    /// Like `with_config`, simulating `world` instead of the process-wide default world.
+    ///
+    /// Engines given separate worlds evolve independently; engines sharing a world share
+    /// its entities.
+    pub fn with_world(
+        arena_capacity: usize,
+        config: EngineConfig,
+        world: EcsWorld,
+    ) -> Result<Self, ConfigError> {
+        config.validate()?;
+        Ok(Self {
+            arena: AmphoreusArena::with_granularity(arena_capacity, config.arena_granularity),
             state: GlobalState::default(),
             flame_chase: FlameChaseHandles::default(),
             world_seed: WorldSeedConfig::default(),
-            persistent_phainon_memory: MemoryLog::default(),
+            world,
+            config,
+            #[cfg(not(target_arch = "wasm32"))]
+            worker_pool: build_worker_pool(config.worker_threads),
+            persistent_memories: vec![PersistentMemory {
+                handle: None,
+                log: MemoryLog::default(),
+                respawn: Self::phainon_spec(),
+            }],
+            black_tides: Vec::new(),
+            pending_perturbation: 0.0,
+            flips_last_tick: 0,
+            failed_spawns: 0,
+            clamped_last_tick: Vec::new(),
+            autosave_prefix: DEFAULT_AUTOSAVE_PREFIX.map(PathBuf::from),
+            pending_despawns: Vec::new(),
+            last_average_corruption: 0.0,
+            corruption_velocity: 0.0,
+            last_entropy: 0.0,
+            entropy_rate: 0.0,
+            last_entity_count: 0,
+            population_rate: 0.0,
+            rng: SimRng::new(config.master_seed),
+        })
+    }
+
+    pub fn config(&self) -> &EngineConfig {
+        &self.config
+    }
+
+    /// Path prefix black tides autosave to; ring slot `n` is written to `<prefix>.<n>.page`.
+    pub fn autosave_prefix(&self) -> Option<&std::path::Path> {
+        self.autosave_prefix.as_deref()
+    }
+
+    /// Moves autosaves to `prefix`, or turns them off with `None`. The prefix is a property
+    /// of the host rather than the simulation, so it is not stored in the page.
+    pub fn set_autosave_prefix(&mut self, prefix: Option<PathBuf>) {
+        self.autosave_prefix = prefix;
+    }
+
+    /// The world this engine simulates.
+    pub fn world(&self) -> &EcsWorld {
+        &self.world
+    }
+
+    /// Every recorded black tide, oldest first, bounded by `black_tide_history_limit`.
+    pub fn black_tides(&self) -> &[BlackTideRecord] {
+        &self.black_tides
+    }
+
+    /// Replaces the engine configuration, rebuilding the worker pool if its size changed.
+    ///
+    /// A changed `master_seed` restarts the engine generator from the new seed.
+    pub fn set_config(&mut self, config: EngineConfig) -> Result<(), ConfigError> {
+        config.validate()?;
+        #[cfg(not(target_arch = "wasm32"))]
+        if config.worker_threads != self.config.worker_threads {
+            self.worker_pool = build_worker_pool(config.worker_threads);
         }
+        if config.master_seed != self.config.master_seed {
+            self.rng = SimRng::new(config.master_seed);
+        }
+        self.arena.set_granularity(config.arena_granularity);
+        self.config = config;
+        Ok(())
     }
 
-    /// Allocates entity storage in the arena, creates an entity, and writes component columns.
-    pub fn spawn_entity(&mut self, spec: SpawnEntitySpec) -> Option<Entity> {
-        let allocation_bytes = size_of::<Entity>()
-            + spec
-                .coreflame
-                .map(|_| size_of::<Coreflame>())
-                .unwrap_or_default()
-            + spec
-                .memory_log
-                .map(|_| size_of::<MemoryLog>())
-                .unwrap_or_default()
-            + spec
-                .golden_blood
-                .map(|_| size_of::<GoldenBlood>())
-                .unwrap_or_default();
+    /// Generator seeded from `master_seed`; every random pass and user system should draw
+    /// from it, in a fixed order, so a whole run replays from one seed.
+    pub fn rng(&mut self) -> &mut SimRng {
+        &mut self.rng
+    }
 
-        let allocation_bytes = allocation_bytes.max(1);
-        self.arena
-            .alloc_bytes(allocation_bytes, align_of::<u64>())
+    /// Runs `op` inside the dedicated worker pool when one is configured.
+    fn run_parallel<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(pool) = &self.worker_pool {
+            return pool.install(op);
+        }
+
+        op()
+    }
+
+    /// Allocates entity storage in the arena, creates an entity, and writes component columns.
+    ///
+    /// The arena bytes stay allocated when the world then refuses the write.
+    pub fn spawn_entity(&mut self, spec: SpawnEntitySpec) -> Result<Entity, SpawnError> {
+        let allocation_bytes = spec.arena_bytes();
+        let spawned = self
+            .arena
+            .alloc_bytes(allocation_bytes, ENTITY_ALIGN)
+            .ok_or(SpawnError::ArenaExhausted)
             .and_then(|_| {
-                with_global_ecs_mut(|ecs| {
-                    let entity = ecs.spawn();
-                    if let Some(coreflame) = spec.coreflame {
-                        ecs.coreflames.insert(entity, coreflame);
-                    }
-                    if let Some(memory_log) = spec.memory_log {
-                        ecs.memory_logs.insert(entity, memory_log);
-                    }
-                    if let Some(golden_blood) = spec.golden_blood {
-                        ecs.golden_blood.insert(entity, golden_blood);
-                    }
-                    entity
+                self.world
+                    .write(|ecs| {
+                        let entity = ecs.spawn_at_cycle(self.state.cycle_count);
+                        if let Some(coreflame) = spec.coreflame {
+                            ecs.coreflames.insert(entity, coreflame);
+                        }
+                        if let Some(memory_log) = spec.memory_log {
+                            ecs.memory_logs.insert(entity, memory_log);
+                        }
+                        if let Some(golden_blood) = spec.golden_blood {
+                            ecs.golden_blood.insert(entity, golden_blood);
+                        }
+                        if let Some(cohort) = spec.cohort {
+                            ecs.cohorts.insert(entity, cohort);
+                        }
+                        if let Some(resistance) = spec.corruption_resistance {
+                            ecs.corruption_resistance.insert(entity, resistance);
+                        }
+                        if let Some(faction) = spec.faction {
+                            ecs.factions.insert(entity, faction);
+                        }
+                        if let Some(lifespan) = spec.lifespan {
+                            ecs.lifespans.insert(entity, lifespan);
+                        }
+                        entity
+                    })
+                    .ok_or(SpawnError::EcsUnavailable)
+            });
+        if spawned.is_err() {
+            self.failed_spawns += 1;
+        }
+        spawned
+    }
+
+    /// Checks that the arena offset matches what `spawn_entity` allocated for this world's
+    /// spawns since the last wipe.
+    ///
+    /// Live entities are costed from their current components. Despawned ones left their
+    /// bytes behind with unknown components, so each widens the accepted range from a bare
+    /// entity to one with every arena component, and small drift can hide in that slack.
+    /// Spawns that bypass `spawn_entity`, failed world writes, components added after spawn,
+    /// and a granularity change since the last wipe are what it catches.
+    pub fn audit_arena_consistency(&self) -> Result<(), ConsistencyError> {
+        let (live_bytes, despawned) = self.world.read(|ecs| {
+            let live_bytes: usize = (0..ecs.entity_span() as Entity)
+                .filter(|&entity| ecs.is_alive(entity))
+                .map(|entity| {
+                    SpawnEntitySpec::arena_footprint(ecs, entity).padded_arena_bytes(&self.arena)
                 })
+                .sum();
+            let despawned = (ecs.spawned_total() as usize).saturating_sub(ecs.entity_count());
+            (live_bytes, despawned)
+        });
+        let bare_entity = SpawnEntitySpec::default().padded_arena_bytes(&self.arena);
+        let full_entity = SpawnEntitySpec {
+            coreflame: Some(Coreflame::default()),
+            memory_log: Some(MemoryLog::default()),
+            golden_blood: Some(GoldenBlood::default()),
+            ..SpawnEntitySpec::default()
+        }
+        .padded_arena_bytes(&self.arena);
+        let expected_min = live_bytes + despawned * bare_entity;
+        let expected_max = live_bytes + despawned * full_entity;
+        let arena_offset = self.arena.offset;
+        // The newest allocation has no trailing padding; the next spawn would add it.
+        let padded_offset =
+            arena_offset.next_multiple_of(ENTITY_ALIGN.max(self.arena.granularity()));
+        if (expected_min..=expected_max).contains(&padded_offset) {
+            Ok(())
+        } else {
+            Err(ConsistencyError::ArenaDrift {
+                arena_offset,
+                expected_min,
+                expected_max,
             })
+        }
+    }
+
+    /// Wipes the world and seeds `seed`'s population plus the flame-chase pair.
+    ///
+    /// Seeding carries on past failed spawns, so the world keeps everything that fit; when
+    /// any spawn failed, the report comes back inside a `SeedError` with the first failure.
+    pub fn seed_world(&mut self, seed: WorldSeedConfig) -> Result<SeedReport, SeedError> {
+        self.seed_world_cancellable(seed, &AtomicBool::new(false))
     }
 
-    pub fn seed_world(&mut self, seed: WorldSeedConfig) {
+    /// Seeds the world, polling `cancel` periodically and stopping early once it is set.
+    ///
+    /// A cancelled seed leaves the partially spawned population in place and skips the
+    /// flame-chase pair; the returned report reflects what was actually spawned. Failed
+    /// spawns are reported as in `seed_world`.
+    pub fn seed_world_cancellable(
+        &mut self,
+        seed: WorldSeedConfig,
+        cancel: &AtomicBool,
+    ) -> Result<SeedReport, SeedError> {
         self.world_seed = seed;
+        if !seed.keep_phainon_memory {
+            self.persistent_memories[PHAINON_MEMORY].log = MemoryLog::default();
+        }
         self.arena.trigger_black_tide();
-        let _ = with_global_ecs_mut(|ecs| ecs.clear_for_black_tide());
+        let _ = self.world.write(|ecs| ecs.clear_for_black_tide());
         self.flame_chase = FlameChaseHandles::default();
 
-        self.seed_population_groups();
-        self.seed_flame_chase_variables();
+        let mut report = SeedReport {
+            spawned: 0,
+            requested: seed
+                .citizens
+                .saturating_add(seed.titans)
+                .saturating_add(seed.chrysos_heirs)
+                .saturating_add(2),
+            cancelled: false,
+        };
+
+        let mut failure = None;
+        self.seed_population_groups(seed, cancel, &mut report, &mut failure);
+        if !report.cancelled {
+            report.spawned += self.seed_flame_chase_variables(&mut failure);
+        }
         self.apply_cyrene_time_exploit();
+        self.reset_rate_baselines();
+        match failure {
+            Some(cause) => Err(SeedError { report, cause }),
+            None => Ok(report),
+        }
     }
 
-    fn seed_population_groups(&mut self) {
-        for idx in 0..self.world_seed.citizens {
-            let power = (0.28 + ((idx % 97) as f64 * 0.004)).clamp(0.0, 1.0);
-            let corruption = ((idx % 37) as f64 * 0.008).clamp(0.0, 0.45);
-            let _ = self.spawn_entity(SpawnEntitySpec {
+    /// Spawns `seed`'s population groups, counting successes into `report` and keeping the
+    /// first failed spawn in `failure`.
+    fn seed_population_groups(
+        &mut self,
+        seed: WorldSeedConfig,
+        cancel: &AtomicBool,
+        report: &mut SeedReport,
+        failure: &mut Option<SpawnError>,
+    ) {
+        // Cyrene spawns last, so her allocation needs no trailing alignment padding.
+        let reserve = if self.config.reserve_flame_chase {
+            self.persistent_memories[PHAINON_MEMORY]
+                .spawn_spec()
+                .padded_arena_bytes(&self.arena)
+                + self.arena.rounded_len(Self::cyrene_spec().arena_bytes())
+        } else {
+            0
+        };
+        let mut rng = SimRng::new(seed.rng_seed);
+        let mut seeded = 0_u32;
+        let mut should_stop = |report: &mut SeedReport| {
+            let check = seeded.is_multiple_of(SEED_CANCEL_CHECK_INTERVAL);
+            seeded = seeded.wrapping_add(1);
+            if check && cancel.load(Ordering::Relaxed) {
+                report.cancelled = true;
+            }
+            report.cancelled
+        };
+
+        for _ in 0..seed.citizens {
+            if should_stop(report) {
+                return;
+            }
+            let power = (0.28 + rng.next_f64() * 0.384).clamp(0.0, 1.0);
+            let corruption = (rng.next_f64() * 0.288).clamp(0.0, 0.45);
+            let spec = SpawnEntitySpec {
                 coreflame: Some(Coreflame {
                     power_level: power,
                     alignment: Path::Erudition,
@@ -155,12 +1027,22 @@ impl AmphoreusEngine {
                 golden_blood: Some(GoldenBlood {
                     corruption_level: corruption,
                 }),
-            });
+                cohort: Some(Cohort::Citizen),
+                corruption_resistance: None,
+                faction: None,
+                lifespan: None,
+            };
+            let spawned = self.spawn_population_entity(reserve, spec);
+            report.spawned += u32::from(spawned.is_ok());
+            *failure = failure.or(spawned.err());
         }
 
-        for idx in 0..self.world_seed.titans {
-            let power = (1.2 + ((idx % 13) as f64 * 0.07)).clamp(0.0, 3.0);
-            let _ = self.spawn_entity(SpawnEntitySpec {
+        for _ in 0..seed.titans {
+            if should_stop(report) {
+                return;
+            }
+            let power = (1.2 + rng.next_f64() * 0.84).clamp(0.0, 3.0);
+            let spec = SpawnEntitySpec {
                 coreflame: Some(Coreflame {
                     power_level: power,
                     alignment: Path::Destruction,
@@ -172,13 +1054,23 @@ impl AmphoreusEngine {
                 golden_blood: Some(GoldenBlood {
                     corruption_level: 0.72,
                 }),
-            });
+                cohort: Some(Cohort::Titan),
+                corruption_resistance: None,
+                faction: None,
+                lifespan: None,
+            };
+            let spawned = self.spawn_population_entity(reserve, spec);
+            report.spawned += u32::from(spawned.is_ok());
+            *failure = failure.or(spawned.err());
         }
 
-        for idx in 0..self.world_seed.chrysos_heirs {
-            let power = (0.9 + ((idx % 11) as f64 * 0.05)).clamp(0.0, 2.0);
-            let trauma = (0.2 + ((idx % 7) as f64 * 0.1)).clamp(0.0, 0.95);
-            let _ = self.spawn_entity(SpawnEntitySpec {
+        for _ in 0..seed.chrysos_heirs {
+            if should_stop(report) {
+                return;
+            }
+            let power = (0.9 + rng.next_f64() * 0.5).clamp(0.0, 2.0);
+            let trauma = (0.2 + rng.next_f64() * 0.6).clamp(0.0, 0.95);
+            let spec = SpawnEntitySpec {
                 coreflame: Some(Coreflame {
                     power_level: power,
                     alignment: Path::Remembrance,
@@ -190,24 +1082,55 @@ impl AmphoreusEngine {
                 golden_blood: Some(GoldenBlood {
                     corruption_level: 0.48,
                 }),
-            });
+                cohort: Some(Cohort::ChrysosHeir),
+                corruption_resistance: Some(CorruptionResistance {
+                    factor: self.config.heir_corruption_resistance,
+                }),
+                faction: None,
+                lifespan: None,
+            };
+            let spawned = self.spawn_population_entity(reserve, spec);
+            report.spawned += u32::from(spawned.is_ok());
+            *failure = failure.or(spawned.err());
+        }
+    }
+
+    /// Spawns a population member only if `reserve` arena bytes remain free afterwards.
+    fn spawn_population_entity(
+        &mut self,
+        reserve: usize,
+        spec: SpawnEntitySpec,
+    ) -> Result<Entity, SpawnError> {
+        if !self
+            .arena
+            .can_alloc(spec.padded_arena_bytes(&self.arena) + reserve, ENTITY_ALIGN)
+        {
+            self.failed_spawns += 1;
+            return Err(SpawnError::ArenaExhausted);
         }
+        self.spawn_entity(spec)
     }
 
-    /// Spawns Phainon and Cyrene, preserving Phainon's memory across black tides.
-    fn seed_flame_chase_variables(&mut self) {
-        let phainon = self.spawn_entity(SpawnEntitySpec {
+    /// Phainon's respawn template; his memory log comes from his persistent memory.
+    fn phainon_spec() -> SpawnEntitySpec {
+        SpawnEntitySpec {
             coreflame: Some(Coreflame {
                 power_level: 1.65,
                 alignment: Path::Remembrance,
             }),
-            memory_log: Some(self.persistent_phainon_memory),
+            memory_log: None,
             golden_blood: Some(GoldenBlood {
                 corruption_level: 0.52,
             }),
-        });
+            cohort: None,
+            corruption_resistance: None,
+            faction: None,
+            lifespan: None,
+        }
+    }
 
-        let cyrene = self.spawn_entity(SpawnEntitySpec {
+    fn cyrene_spec() -> SpawnEntitySpec {
+        SpawnEntitySpec {
             coreflame: Some(Coreflame {
                 power_level: 1.35,
                 alignment: Path::Remembrance,
@@ -219,16 +1142,123 @@ impl AmphoreusEngine {
             golden_blood: Some(GoldenBlood {
                 corruption_level: 0.33,
             }),
-        });
+            cohort: None,
+            corruption_resistance: None,
+            faction: None,
+            lifespan: None,
+        }
+    }
 
+    /// Spawns Phainon with his persistent memory and Cyrene, then respawns every other
+    /// persistent memory whose entity is gone.
+    ///
+    /// Returns how many of the flame-chase pair were actually spawned, keeping the first of
+    /// their failed spawns in `failure`. Other persistent memories that fail to respawn are
+    /// retried on the next reseed.
+    fn seed_flame_chase_variables(&mut self, failure: &mut Option<SpawnError>) -> u32 {
+        let phainon = self.respawn_persistent_memory(PHAINON_MEMORY);
+        let cyrene = self
+            .spawn_entity(Self::cyrene_spec())
+            .and_then(|entity| self.handle_of_spawned(entity));
+        *failure = failure.or(phainon.err()).or(cyrene.err());
+        let (phainon, cyrene) = (phainon.ok(), cyrene.ok());
         self.flame_chase = FlameChaseHandles { phainon, cyrene };
+
+        for index in PHAINON_MEMORY + 1..self.persistent_memories.len() {
+            let handle = self.persistent_memories[index].handle;
+            let alive =
+                handle.is_some_and(|handle| self.world.read(|ecs| ecs.is_handle_alive(handle)));
+            if !alive {
+                let _ = self.respawn_persistent_memory(index);
+            }
+        }
+        u32::from(phainon.is_some()) + u32::from(cyrene.is_some())
     }
 
-    fn apply_cyrene_time_exploit(&mut self) {
-        let cyrene = self.flame_chase.cyrene;
-        let exploit_active = cyrene
-            .and_then(|entity| {
-                with_global_ecs(|ecs| {
+    /// Spawns a fresh entity for persistent memory `index` and points the memory at it.
+    fn respawn_persistent_memory(&mut self, index: usize) -> Result<EntityHandle, SpawnError> {
+        let spec = self.persistent_memories[index].spawn_spec();
+        let handle = self
+            .spawn_entity(spec)
+            .and_then(|entity| self.handle_of_spawned(entity));
+        self.persistent_memories[index].handle = handle.ok();
+        handle
+    }
+
+    /// Handle to an entity `spawn_entity` just created.
+    fn handle_of_spawned(&self, entity: Entity) -> Result<EntityHandle, SpawnError> {
+        self.world
+            .read(|ecs| ecs.handle(entity))
+            .ok_or(SpawnError::EcsUnavailable)
+    }
+
+    /// Carries `entity`'s memory across black tides and reseeds the way Phainon's is.
+    ///
+    /// The entity's current components become its respawn template, and its memory log, or
+    /// a default one, becomes the persistent log. The memory pass advances it every tick,
+    /// memory aging skips it, and each reseed spawns a fresh entity for it once the old one
+    /// is gone. An entity without a memory log gets one on its first respawn. Returns false
+    /// when `entity` is dead or already registered.
+    pub fn register_persistent_memory(&mut self, entity: Entity) -> bool {
+        let memory = self.world.read(|ecs| {
+            let handle = ecs.handle(entity)?;
+            Some(PersistentMemory {
+                handle: Some(handle),
+                log: ecs.memory_logs.get(entity).copied().unwrap_or_default(),
+                respawn: SpawnEntitySpec {
+                    memory_log: None,
+                    cohort: ecs.cohorts.get(entity).copied(),
+                    corruption_resistance: ecs.corruption_resistance.get(entity).copied(),
+                    faction: ecs.factions.get(entity).copied(),
+                    lifespan: ecs.lifespans.get(entity).copied(),
+                    ..SpawnEntitySpec::arena_footprint(ecs, entity)
+                },
+            })
+        });
+        let Some(memory) = memory else {
+            return false;
+        };
+        if self
+            .persistent_memories
+            .iter()
+            .any(|registered| registered.handle == memory.handle)
+        {
+            return false;
+        }
+        self.persistent_memories.push(memory);
+        true
+    }
+
+    /// Every persistent memory, Phainon's first.
+    pub fn persistent_memories(&self) -> &[PersistentMemory] {
+        &self.persistent_memories
+    }
+
+    /// Reads the tracked Phainon and Cyrene components into a `FlameChaseSnapshot`.
+    pub fn flame_chase_snapshot(&self) -> FlameChaseSnapshot {
+        let memory_of = |handle: Option<EntityHandle>| {
+            handle.and_then(|handle| {
+                self.world
+                    .read(|ecs| ecs.memory_logs.get(ecs.resolve(handle)?).copied())
+            })
+        };
+        let phainon_memory = memory_of(self.flame_chase.phainon);
+        let cyrene_memory = memory_of(self.flame_chase.cyrene);
+
+        FlameChaseSnapshot {
+            phainon_trauma: phainon_memory.map(|memory| memory.trauma_index),
+            phainon_cycles: phainon_memory.map(|memory| memory.retained_cycles),
+            cyrene_trauma: cyrene_memory.map(|memory| memory.trauma_index),
+            exploit_active: !self.state.time_concept_active,
+        }
+    }
+
+    fn apply_cyrene_time_exploit(&mut self) {
+        let cyrene = self.flame_chase.cyrene;
+        let exploit_active = cyrene
+            .and_then(|handle| {
+                self.world.read(|ecs| {
+                    let entity = ecs.resolve(handle)?;
                     let coreflame = ecs.coreflames.get(entity)?;
                     let memory = ecs.memory_logs.get(entity)?;
                     Some(
@@ -237,66 +1267,143 @@ impl AmphoreusEngine {
                             && coreflame.power_level >= 1.0,
                     )
                 })
-                .flatten()
             })
             .unwrap_or(false);
 
         self.state.time_concept_active = !exploit_active;
     }
 
-    fn advance_phainon_memory(&mut self) {
-        self.persistent_phainon_memory.retained_cycles = self
-            .persistent_phainon_memory
-            .retained_cycles
-            .saturating_add(1);
-        self.persistent_phainon_memory.trauma_index = (self.persistent_phainon_memory.trauma_index
-            + (self.state.destruction_entropy * 0.02))
-            .clamp(0.0, 1.0);
-
-        if let Some(phainon) = self.flame_chase.phainon {
-            let memory = self.persistent_phainon_memory;
-            let _ = with_global_ecs_mut(|ecs| {
-                if let Some(memory_log) = ecs.memory_logs.get_mut(phainon) {
-                    *memory_log = memory;
-                }
-            });
+    fn advance_persistent_memories(&mut self) {
+        let entropy = self.state.destruction_entropy;
+        for memory in &mut self.persistent_memories {
+            memory.log.retained_cycles = memory.log.retained_cycles.saturating_add(1);
+            memory.log.trauma_index = (memory.log.trauma_index + (entropy * 0.02)).clamp(0.0, 1.0);
         }
+
+        let memories = &self.persistent_memories;
+        let _ = self.world.write(|ecs| {
+            for memory in memories {
+                if let Some(entity) = memory.handle.and_then(|handle| ecs.resolve(handle)) {
+                    ecs.modify_memory_log(entity, |memory_log| *memory_log = memory.log);
+                }
+            }
+        });
     }
 
-    fn capture_phainon_memory(&mut self) {
-        if let Some(phainon) = self.flame_chase.phainon {
-            if let Some(memory_log) =
-                with_global_ecs(|ecs| ecs.memory_logs.get(phainon).copied()).flatten()
+    /// Pulls each living persistent entity's memory log back into its persistent memory.
+    fn capture_persistent_memories(&mut self) {
+        let world = &self.world;
+        for memory in &mut self.persistent_memories {
+            if let Some(handle) = memory.handle
+                && let Some(memory_log) =
+                    world.read(|ecs| ecs.memory_logs.get(ecs.resolve(handle)?).copied())
             {
-                self.persistent_phainon_memory = memory_log;
+                memory.log = memory_log;
             }
         }
     }
 
-    fn reseed_after_black_tide(&mut self) {
+    /// Wipes the arena and world, keeping living Chrysos heirs under
+    /// `preserve_chrysos_heirs`; returns how many survived.
+    fn wipe_for_black_tide(&mut self) -> u32 {
+        self.arena.trigger_black_tide();
+        if !self.config.preserve_chrysos_heirs {
+            let _ = self.world.write(|ecs| ecs.clear_for_black_tide());
+            return 0;
+        }
+
+        let arena = &mut self.arena;
+        self.world
+            .write(|ecs| {
+                let heirs: Vec<Entity> = ecs
+                    .cohorts
+                    .iter()
+                    .filter(|&(_, &cohort)| cohort == Cohort::ChrysosHeir)
+                    .map(|(entity, _)| entity)
+                    .collect();
+                ecs.clear_except(&heirs);
+                // Survivors carry their arena footprint into the new cycle.
+                for &heir in &heirs {
+                    let bytes = SpawnEntitySpec::arena_footprint(ecs, heir).arena_bytes();
+                    let _ = arena.alloc_bytes(bytes, ENTITY_ALIGN);
+                }
+                heirs.len() as u32
+            })
+            .unwrap_or(0)
+    }
+
+    fn reseed_after_black_tide(&mut self, surviving_heirs: u32) {
         self.flame_chase = FlameChaseHandles::default();
-        self.seed_population_groups();
-        self.seed_flame_chase_variables();
+        let mut seed = self
+            .config
+            .reseed_policy
+            .scaled(self.world_seed, self.state.destruction_entropy);
+        seed.chrysos_heirs = seed.chrysos_heirs.saturating_sub(surviving_heirs);
+        let mut failure = None;
+        let mut report = SeedReport::default();
+        self.seed_population_groups(seed, &AtomicBool::new(false), &mut report, &mut failure);
+        let _ = self.seed_flame_chase_variables(&mut failure);
         self.apply_cyrene_time_exploit();
     }
 
     pub fn tick(&mut self) -> SimulationResult {
-        self.apply_cyrene_time_exploit();
+        for pass in self.config.pass_order {
+            if !self.state.time_concept_active && !self.config.time_bypass_policy.runs(pass) {
+                continue;
+            }
+            match pass {
+                EnginePass::TimeExploit => self.apply_cyrene_time_exploit(),
+                EnginePass::Entropy => {
+                    let nodes = self.build_destruction_nodes();
+                    self.state.destruction_entropy =
+                        evaluate_destruction_ast_with(&nodes, &self.config.eval);
+                }
+                EnginePass::PhainonMemory => self.advance_persistent_memories(),
+                EnginePass::Corruption => {
+                    self.apply_golden_blood_corruption();
+                    self.apply_zero_power_policy();
+                }
+                EnginePass::MemoryAging => {
+                    let memories = &self.persistent_memories;
+                    let pending = &mut self.pending_despawns;
+                    let _ = self.world.write(|ecs| {
+                        let preserved: Vec<Entity> = memories
+                            .iter()
+                            .filter_map(|memory| ecs.resolve(memory.handle?))
+                            .collect();
+                        ecs.age_memory_logs(&preserved);
+                        ecs.age_lifespans(pending);
+                    });
+                    self.flush_pending_despawns();
+                }
+            }
+        }
         let time_bypassed = !self.state.time_concept_active;
+        let _ = self.world.write(|ecs| ecs.flush_watch_events());
 
-        let nodes = self.build_destruction_nodes();
-        self.state.destruction_entropy = evaluate_destruction_ast(&nodes);
+        let (average_corruption, entity_count) = self
+            .world
+            .read(|ecs| (ecs.average_corruption(), ecs.entity_count()));
+        self.corruption_velocity = average_corruption - self.last_average_corruption;
+        self.last_average_corruption = average_corruption;
+        self.population_rate = (entity_count as f64 - self.last_entity_count as f64)
+            / self.last_entity_count.max(1) as f64;
+        self.last_entity_count = entity_count;
 
-        self.advance_phainon_memory();
-        self.apply_golden_blood_corruption();
+        if self.pending_perturbation != 0.0 {
+            self.state.destruction_entropy =
+                (self.state.destruction_entropy + self.pending_perturbation).clamp(0.0, 1.0);
+            self.pending_perturbation = 0.0;
+        }
+        self.entropy_rate = self.state.destruction_entropy - self.last_entropy;
+        self.last_entropy = self.state.destruction_entropy;
 
-        if self.state.destruction_entropy >= 1.0 {
-            self.capture_phainon_memory();
-            self.snapshot_to_eternal_page("amphoreus_autosave.page");
-            self.arena.trigger_black_tide();
-            let _ = with_global_ecs_mut(|ecs| ecs.clear_for_black_tide());
-            self.state.cycle_count = self.state.cycle_count.saturating_add(1);
-            self.reseed_after_black_tide();
+        if self
+            .config
+            .black_tide_trigger
+            .fires(self.state.destruction_entropy, &mut self.rng)
+        {
+            self.run_black_tide();
             return SimulationResult::BlackTideTriggered;
         }
 
@@ -308,106 +1415,3359 @@ impl AmphoreusEngine {
         SimulationResult::TickAdvanced
     }
 
-    /// Serializes the used byte-state of the arena to a `.page` file.
+    /// Runs `ticks` ticks and returns every crossing of `watermarks` by destruction entropy.
+    ///
+    /// Each tick compares the entropy before and after it, so the reset after a black tide
+    /// shows up as downward crossings on the following tick.
+    pub fn run_fixed(&mut self, ticks: u64, watermarks: &[f64]) -> Vec<EntropyCrossing> {
+        let mut crossings = Vec::new();
+        for tick in 0..ticks {
+            let previous = self.state.destruction_entropy;
+            self.tick();
+            record_entropy_crossings(
+                &mut crossings,
+                tick,
+                previous,
+                self.state.destruction_entropy,
+                watermarks,
+            );
+        }
+        crossings
+    }
+
+    /// Fires a black tide now, whatever the current entropy.
+    ///
+    /// Runs exactly the sequence a tick would: Phainon's memory is captured, the tide is
+    /// recorded and autosaved, the world is wiped and reseeded, and `cycle_count` advances.
+    pub fn force_black_tide(&mut self) {
+        self.run_black_tide();
+    }
+
+    /// Records, autosaves, wipes, and reseeds the world.
+    fn run_black_tide(&mut self) {
+        self.capture_persistent_memories();
+        self.record_black_tide();
+        if let Some(prefix) = &self.autosave_prefix {
+            let ring_size = self.config.autosave_ring_size.max(1) as u64;
+            let slot = (self.state.cycle_count % ring_size) as usize;
+            self.write_eternal_page(&autosave_path(prefix, slot));
+        }
+        let surviving_heirs = self.wipe_for_black_tide();
+        self.state.cycle_count = self.state.cycle_count.saturating_add(1);
+        self.reseed_after_black_tide(surviving_heirs);
+        // Measure the next tick against the reseeded world, not the one that collapsed.
+        self.reset_rate_baselines();
+    }
+
+    /// Restarts the per-tick rate baselines from the world as it is now.
+    fn reset_rate_baselines(&mut self) {
+        let (average_corruption, entity_count) = self
+            .world
+            .read(|ecs| (ecs.average_corruption(), ecs.entity_count()));
+        self.last_average_corruption = average_corruption;
+        self.last_entity_count = entity_count;
+        self.last_entropy =
+            evaluate_destruction_ast_with(&self.build_destruction_nodes(), &self.config.eval);
+    }
+
+    fn record_black_tide(&mut self) {
+        let (entity_count, average_corruption, cohorts) = self.world.read(|ecs| {
+            let cohorts = CohortReport {
+                citizens: ecs.cohort_stats(Cohort::Citizen),
+                titans: ecs.cohort_stats(Cohort::Titan),
+                chrysos_heirs: ecs.cohort_stats(Cohort::ChrysosHeir),
+            };
+            (ecs.entity_count(), ecs.average_corruption(), cohorts)
+        });
+        self.black_tides.push(BlackTideRecord {
+            cycle: self.state.cycle_count,
+            entropy: self.state.destruction_entropy,
+            entity_count,
+            average_corruption,
+            cohorts,
+        });
+
+        let limit = self.config.black_tide_history_limit;
+        if self.black_tides.len() > limit {
+            let excess = self.black_tides.len() - limit;
+            self.black_tides.drain(..excess);
+        }
+    }
+
+    /// Serializes global state, engine config, the used arena bytes, and the engine's world to
+    /// a `.page` file.
     pub fn snapshot_to_eternal_page(&self, file_path: &str) {
-        let snapshot = ArenaSnapshot {
-            offset: self.arena.offset,
-            memory: self.arena.used_bytes(),
+        self.write_eternal_page(std::path::Path::new(file_path));
+    }
+
+    fn write_eternal_page(&self, file_path: &std::path::Path) {
+        let encoded = self
+            .world
+            .read(|world| {
+                // Stale handles are dropped so a restore cannot retake a slot someone else now holds.
+                let live = |handle: Option<EntityHandle>| {
+                    handle.filter(|&handle| world.is_handle_alive(handle))
+                };
+                let snapshot = EternalPage {
+                    state: self.state,
+                    config: self.config,
+                    world_seed: self.world_seed,
+                    arena_capacity: self.arena.memory.len(),
+                    arena_offset: self.arena.offset,
+                    arena_memory: self.arena.used_bytes(),
+                    black_tides: self.black_tides.clone(),
+                    rng: self.rng,
+                    flame_chase: FlameChaseHandles {
+                        phainon: live(self.flame_chase.phainon),
+                        cyrene: live(self.flame_chase.cyrene),
+                    },
+                    persistent_memories: self
+                        .persistent_memories
+                        .iter()
+                        .map(|&memory| PersistentMemory {
+                            handle: live(memory.handle),
+                            ..memory
+                        })
+                        .collect(),
+                    ecs: Some(world),
+                };
+                encode_to_vec(&snapshot, standard())
+            })
+            .map_err(|err| err.to_string())
+            .and_then(|payload| {
+                page::encode_page(&payload, self.config.page_compression)
+                    .map_err(|err| err.to_string())
+            });
+
+        match encoded {
+            Ok(bytes) => {
+                if let Err(err) = fs::write(file_path, bytes) {
+                    eprintln!(
+                        "failed to write eternal page `{}`: {err}",
+                        file_path.display()
+                    );
+                }
+            }
+            Err(err) => {
+                eprintln!(
+                    "failed to serialize eternal page `{}`: {err}",
+                    file_path.display()
+                );
+            }
+        }
+    }
+
+    /// Writes only the global state, config, and black-tide count to a small checkpoint file,
+    /// skipping the arena and the ECS world.
+    pub fn save_state_only(&self, file_path: &str) {
+        let checkpoint = StateCheckpoint {
+            state: self.state,
+            config: self.config,
+            black_tide_count: self.black_tides.len(),
         };
 
-        match encode_to_vec(&snapshot, standard()) {
+        let encoded = encode_to_vec(checkpoint, standard())
+            .map_err(|err| err.to_string())
+            .and_then(|payload| page::encode_state(&payload).map_err(|err| err.to_string()));
+
+        match encoded {
             Ok(bytes) => {
                 if let Err(err) = fs::write(file_path, bytes) {
-                    eprintln!("failed to write eternal page `{file_path}`: {err}");
+                    eprintln!("failed to write state checkpoint `{file_path}`: {err}");
                 }
             }
             Err(err) => {
-                eprintln!("failed to serialize eternal page `{file_path}`: {err}");
+                eprintln!("failed to serialize state checkpoint `{file_path}`: {err}");
+            }
+        }
+    }
+
+    /// Reads a checkpoint written by `save_state_only`, validating its config.
+    pub fn load_state_only(file_path: &str) -> Result<StateCheckpoint, RestoreError> {
+        let checkpoint = page::read_state(file_path)?;
+        checkpoint.config.validate()?;
+        Ok(checkpoint)
+    }
+
+    /// Rebuilds an engine from a `.page` file written by `snapshot_to_eternal_page`.
+    ///
+    /// State, config, seed parameters, the generator, the black-tide ledger, persistent
+    /// memories, arena bytes, and the whole world are restored. The saved world becomes a fresh
+    /// `EcsWorld` owned by the returned engine, leaving every other world, including the
+    /// process-wide default one, untouched. Entity IDs, generations, and the free list are
+    /// intact, so the saved flame-chase and persistent-memory handles resolve as they did; the
+    /// world's watches are not saved. Pages from older layouts are rejected with
+    /// `RestoreError::UnsupportedVersion`.
+    pub fn restore_from_eternal_page(file_path: &str) -> Result<Self, RestoreError> {
+        Self::from_page(page::read_page(file_path)?)
+    }
+
+    fn from_page(page: EternalPage<Vec<u8>, SoaEcs>) -> Result<Self, RestoreError> {
+        let world = page
+            .ecs
+            .map_or_else(|| EcsWorld::new(0), EcsWorld::from_ecs);
+        let mut engine = Self::with_world(page.arena_capacity, page.config, world)?;
+        let restored_len = page.arena_memory.len().min(engine.arena.memory.len());
+        engine.arena.memory[..restored_len].copy_from_slice(&page.arena_memory[..restored_len]);
+        engine.arena.offset = page.arena_offset.min(engine.arena.memory.len());
+        engine.state = page.state;
+        engine.world_seed = page.world_seed;
+        engine.black_tides = page.black_tides;
+        engine.rng = page.rng;
+        if !page.persistent_memories.is_empty() {
+            engine.persistent_memories = page.persistent_memories;
+        }
+        engine.flame_chase = page.flame_chase;
+        engine.reset_rate_baselines();
+        Ok(engine)
+    }
+
+    /// Restores from the autosave ring at `prefix`, choosing the page with the highest stored
+    /// cycle count among slots `0..ring_size` that read back cleanly.
+    ///
+    /// Pages that fail their checksum, or fail to load for any other reason, are skipped. The
+    /// restored engine keeps autosaving to `prefix`. When no slot loads, the first error other
+    /// than a missing file is returned, or `NotFound` if the ring is empty.
+    pub fn restore_latest_valid(
+        prefix: impl Into<PathBuf>,
+        ring_size: usize,
+    ) -> Result<Self, RestoreError> {
+        let prefix = prefix.into();
+        let mut pages = Vec::new();
+        let mut first_error = None;
+        for slot in 0..ring_size.max(1) {
+            match page::read_page(autosave_path(&prefix, slot)) {
+                Ok(page) => pages.push(page),
+                Err(RestoreError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
             }
         }
+        pages.sort_by_key(|page| std::cmp::Reverse(page.state.cycle_count));
+
+        for page in pages {
+            match Self::from_page(page) {
+                Ok(mut engine) => {
+                    engine.autosave_prefix = Some(prefix);
+                    return Ok(engine);
+                }
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+        Err(first_error.unwrap_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "no autosave pages found").into()
+        }))
     }
 
     fn build_destruction_nodes(&self) -> Vec<DestructionNode> {
-        let entity_count = with_global_ecs(|ecs| ecs.entity_count() as u32).unwrap_or(0);
-        let average_corruption = with_global_ecs(|ecs| ecs.average_corruption()).unwrap_or(0.0);
-        let memory_multiplier = 1.0 + self.persistent_phainon_memory.trauma_index * 0.25;
+        let entity_count = self.world.read(|ecs| ecs.entity_count() as u32);
+        let average_corruption = self.world.read(|ecs| ecs.average_corruption());
+        self.destruction_nodes(entity_count, average_corruption)
+    }
 
-        vec![
-            DestructionNode::EntityCount(entity_count),
-            DestructionNode::ConflictEvent(average_corruption),
-            DestructionNode::EntropyMultiplier((1.0 + average_corruption * 0.35) * memory_multiplier),
-        ]
+    fn destruction_nodes(
+        &self,
+        entity_count: u32,
+        average_corruption: f64,
+    ) -> Vec<DestructionNode> {
+        let trauma_index = self.persistent_memories[PHAINON_MEMORY].log.trauma_index;
+        self.destruction_nodes_at(entity_count, average_corruption, trauma_index)
     }
 
-    pub fn apply_golden_blood_corruption(&mut self) {
-        let local_entropy = self.state.destruction_entropy;
+    /// `destruction_nodes` with Phainon's trauma index overridden.
+    fn destruction_nodes_at(
+        &self,
+        entity_count: u32,
+        average_corruption: f64,
+        trauma_index: f64,
+    ) -> Vec<DestructionNode> {
+        let cycles = self.persistent_memories[PHAINON_MEMORY].log.retained_cycles as f64;
+        let saturation = self.config.retained_cycle_saturation.max(1.0);
+        let cycle_weight = self.config.retained_cycle_weight * cycles / (cycles + saturation);
+        let memory_multiplier = 1.0 + trauma_index * 0.25 + cycle_weight;
 
-        let _ = with_global_ecs_mut(|ecs| {
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                let entity_span = ecs.entity_span();
-                let mut corruption_lookup = vec![0.0_f64; entity_span];
-
-                let (golden_entities, golden_data) = ecs.golden_blood.dense_pairs_mut();
-                let updates: Vec<(Entity, f64)> = golden_entities
-                    .par_iter()
-                    .copied()
-                    .zip(golden_data.par_iter_mut())
-                    .filter_map(|(entity, blood)| {
-                        if blood.corruption_level < 0.6 {
-                            return None;
-                        }
+        let population = match self.config.entity_scaling {
+            EntityScaling::Linear => DestructionNode::EntityCount(entity_count),
+            EntityScaling::Logarithmic => DestructionNode::LogEntityCount(entity_count),
+        };
+        vec![DestructionNode::Product(vec![
+            DestructionNode::Sum(vec![
+                population,
+                DestructionNode::ConflictEvent(average_corruption),
+            ]),
+            DestructionNode::EntropyMultiplier(
+                (1.0 + average_corruption * self.config.corruption_to_entropy) * memory_multiplier,
+            ),
+        ])]
+    }
 
-                        blood.corruption_level =
-                            (blood.corruption_level + (local_entropy * 0.05)).clamp(0.0, 1.0);
-                        Some((entity, blood.corruption_level))
-                    })
-                    .collect();
+    /// The destruction AST the next entropy pass would evaluate, as JSON.
+    pub fn export_equation(&self) -> String {
+        serde_json::to_string(&self.build_destruction_nodes())
+            .expect("destruction nodes always serialize")
+    }
 
-                for (entity, corruption_level) in updates {
-                    let index = entity as usize;
-                    if index < corruption_lookup.len() {
-                        corruption_lookup[index] = corruption_level;
-                    }
-                }
+    /// Parses an AST written by `export_equation`, ready for `evaluate_destruction_ast_with`.
+    pub fn import_equation(json: &str) -> Result<Vec<DestructionNode>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
 
-                let (coreflame_entities, coreflame_data) = ecs.coreflames.dense_pairs_mut();
-                coreflame_entities
-                    .par_iter()
-                    .copied()
-                    .zip(coreflame_data.par_iter_mut())
-                    .for_each(|(entity, coreflame)| {
-                        let corruption_level =
-                            corruption_lookup.get(entity as usize).copied().unwrap_or(0.0);
-                        if corruption_level <= 0.0 {
-                            return;
-                        }
+    /// How much spawning `spec` would change entropy, evaluated against the world as it is now.
+    ///
+    /// Read-only: the entity is only added to the entity count and, if it carries golden
+    /// blood, to the average corruption. Whether the arena has room for it is not checked.
+    pub fn entropy_delta_if_spawned(&self, spec: &SpawnEntitySpec) -> f64 {
+        let (entity_count, blood_count, total_corruption) = self.world.read(|ecs| {
+            (
+                ecs.entity_count() as u32,
+                ecs.golden_blood.len(),
+                ecs.total_corruption(),
+            )
+        });
+        let average = |total: f64, count: usize| {
+            if count == 0 {
+                0.0
+            } else {
+                total / count as f64
+            }
+        };
 
-                        coreflame.power_level =
-                            (coreflame.power_level * (1.0 - corruption_level * 0.03)).max(0.0);
-                        coreflame.alignment = Path::Destruction;
-                    });
+        let current = self.destruction_nodes(entity_count, average(total_corruption, blood_count));
+        let hypothetical_corruption = match spec.golden_blood {
+            Some(blood) => average(total_corruption + blood.corruption_level, blood_count + 1),
+            None => average(total_corruption, blood_count),
+        };
+        let hypothetical =
+            self.destruction_nodes(entity_count.saturating_add(1), hypothetical_corruption);
+
+        evaluate_destruction_ast_with(&hypothetical, &self.config.eval)
+            - evaluate_destruction_ast_with(&current, &self.config.eval)
+    }
+
+    /// Partial derivatives of entropy with respect to each AST input, by central differences
+    /// around the world as it is now.
+    ///
+    /// Read-only. Evaluated without `EvalConfig` rounding, which would flatten the small
+    /// differences; where clamping saturates entropy the derivatives are zero. Steps are one
+    /// entity and `ENTROPY_SENSITIVITY_STEP`, one-sided at the edges of each input's range.
+    pub fn entropy_sensitivities(&self) -> EntropySensitivity {
+        let (entity_count, average_corruption) = self
+            .world
+            .read(|ecs| (ecs.entity_count() as u32, ecs.average_corruption()));
+        let trauma_index = self.persistent_memories[PHAINON_MEMORY].log.trauma_index;
+        let entropy = |count: u32, corruption: f64, trauma: f64| {
+            evaluate_destruction_ast(&self.destruction_nodes_at(count, corruption, trauma))
+        };
+        let unit_difference = |x: f64, at: &dyn Fn(f64) -> f64| {
+            let low = (x - ENTROPY_SENSITIVITY_STEP).max(0.0);
+            let high = (x + ENTROPY_SENSITIVITY_STEP).min(1.0);
+            if high <= low {
+                return 0.0;
             }
+            (at(high) - at(low)) / (high - low)
+        };
 
-            #[cfg(target_arch = "wasm32")]
-            {
-                let (coreflames, golden_blood) = (&mut ecs.coreflames, &mut ecs.golden_blood);
-                for (entity, blood) in golden_blood.iter_mut() {
-                    if blood.corruption_level < 0.6 {
-                        continue;
+        let fewer = entity_count.saturating_sub(1);
+        let more = entity_count.saturating_add(1);
+        let d_entity_count = if more > fewer {
+            (entropy(more, average_corruption, trauma_index)
+                - entropy(fewer, average_corruption, trauma_index))
+                / f64::from(more - fewer)
+        } else {
+            0.0
+        };
+
+        EntropySensitivity {
+            d_entity_count,
+            d_corruption: unit_difference(average_corruption, &|corruption| {
+                entropy(entity_count, corruption, trauma_index)
+            }),
+            d_trauma: unit_difference(trauma_index, &|trauma| {
+                entropy(entity_count, average_corruption, trauma)
+            }),
+        }
+    }
+
+    /// Schedules a one-off entropy shift applied on the next tick, before the black-tide check.
+    ///
+    /// Repeated calls before that tick accumulate; the result is clamped to `[0.0, 1.0]`.
+    pub fn perturb_entropy(&mut self, delta: f64) {
+        if delta.is_finite() {
+            self.pending_perturbation += delta;
+        }
+    }
+
+    /// Exports the `top_n` most threatening entities and their components.
+    ///
+    /// Entities appear in descending threat order, ties broken by entity ID.
+    pub fn export_threat_subset(&self, top_n: usize) -> EcsSnapshot {
+        let mut threats = self.scored_threats();
+        if top_n < threats.len() {
+            threats.select_nth_unstable_by(top_n, rank_threats);
+            threats.truncate(top_n);
+        }
+        threats.sort_unstable_by(rank_threats);
+
+        let entities: Vec<Entity> = threats.into_iter().map(|(entity, _)| entity).collect();
+        self.world.read(|ecs| ecs.snapshot_entities(&entities))
+    }
+
+    /// Returns one page of entities and their threat scores in descending threat order.
+    ///
+    /// Ties are broken by entity ID, so pages never overlap or skip entities while the world
+    /// is unchanged. Only the requested page is fully sorted.
+    pub fn threats_ranked(&self, offset: usize, limit: usize) -> Vec<(Entity, f64)> {
+        let mut threats = self.scored_threats();
+        if offset >= threats.len() || limit == 0 {
+            return Vec::new();
+        }
+
+        let end = offset.saturating_add(limit);
+        if end < threats.len() {
+            threats.select_nth_unstable_by(end, rank_threats);
+            threats.truncate(end);
+        }
+        if offset > 0 {
+            threats.select_nth_unstable_by(offset, rank_threats);
+        }
+
+        let mut page = threats.split_off(offset);
+        page.sort_unstable_by(rank_threats);
+        page
+    }
+
+    /// Threat score of every entity carrying a coreflame, in storage order.
+    fn scored_threats(&self) -> Vec<(Entity, f64)> {
+        self.world.read(|ecs| {
+            ecs.coreflames
+                .dense_entities()
+                .iter()
+                .filter_map(|&entity| Some((entity, ecs.threat_score(entity)?)))
+                .collect()
+        })
+    }
+
+    /// Lists entities close enough to the spread threshold to flip on the next corruption pass.
+    ///
+    /// Each entity's corruption is projected forward by the blood gain the pass would apply:
+    /// `destruction_entropy * entropy_to_corruption`, scaled by the entity's corruption
+    /// resistance. The entity is listed when that projection lands within `margin` below
+    /// `corruption_spread_threshold` or past it. Entities already aligned with Destruction are
+    /// excluded, as are entities without a coreflame since they have nothing left to flip.
+    pub fn entities_near_flip(&self, margin: f64) -> Vec<Entity> {
+        let lower_bound = self.config.corruption_spread_threshold - margin.max(0.0);
+        let blood_gain = self.state.destruction_entropy * self.config.entropy_to_corruption;
+
+        self.world.read(|ecs| {
+            ecs.golden_blood
+                .iter()
+                .filter(|(entity, blood)| {
+                    let gain = blood_gain * resistance_factor(&ecs.corruption_resistance, *entity);
+                    (blood.corruption_level + gain).clamp(0.0, 1.0) >= lower_bound
+                })
+                .filter(|(entity, _)| {
+                    ecs.coreflames
+                        .get(*entity)
+                        .is_some_and(|coreflame| coreflame.alignment != Path::Destruction)
+                })
+                .map(|(entity, _)| entity)
+                .collect()
+        })
+    }
+
+    /// Lowers the corruption of every listed entity by `amount` under one ECS write lock.
+    ///
+    /// An entity listed more than once is purified once, and entities without golden blood
+    /// are skipped; returns how many distinct entities were purified.
+    pub fn purify_batch(&mut self, entities: &[Entity], amount: f64) -> usize {
+        let amount = amount.max(0.0);
+        let mut distinct = entities.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+
+        self.world
+            .write(|ecs| {
+                let mut purified = 0;
+                for entity in distinct {
+                    if ecs.modify_golden_blood(entity, |blood| {
+                        blood.corruption_level = (blood.corruption_level - amount).clamp(0.0, 1.0);
+                    }) {
+                        purified += 1;
                     }
+                }
+                purified
+            })
+            .unwrap_or(0)
+    }
 
-                    blood.corruption_level =
-                        (blood.corruption_level + (local_entropy * 0.05)).clamp(0.0, 1.0);
+    /// Applies the configured `ZeroPowerPolicy` to every coreflame whose power has reached zero.
+    pub fn apply_zero_power_policy(&mut self) {
+        let policy = self.config.zero_power_policy;
+        if policy == ZeroPowerPolicy::Keep {
+            return;
+        }
 
-                    if let Some(coreflame) = coreflames.get_mut(entity) {
-                        coreflame.power_level =
-                            (coreflame.power_level * (1.0 - blood.corruption_level * 0.03))
-                                .max(0.0);
-                        coreflame.alignment = Path::Destruction;
+        let _ = self.world.write(|ecs| match policy {
+            ZeroPowerPolicy::Keep => {}
+            ZeroPowerPolicy::Despawn => {
+                let husks: Vec<Entity> = ecs
+                    .coreflames
+                    .iter()
+                    .filter(|(_, coreflame)| coreflame.power_level <= 0.0)
+                    .map(|(entity, _)| entity)
+                    .collect();
+                for entity in husks {
+                    let _ = ecs.despawn(entity);
+                }
+            }
+            ZeroPowerPolicy::Revert => {
+                let mut reverted = Vec::new();
+                for (entity, coreflame) in ecs.coreflames.iter_mut() {
+                    if coreflame.power_level <= 0.0 && coreflame.alignment != Path::None {
+                        coreflame.alignment = Path::None;
+                        reverted.push(entity);
                     }
                 }
+                for entity in reverted {
+                    ecs.mark_changed(entity, WatchMask::COREFLAME);
+                }
+            }
+        });
+    }
+
+    /// Signed change in average golden-blood corruption over the most recent tick.
+    ///
+    /// After `seed_world` or a black tide the baseline is the freshly seeded world, so the
+    /// wipe itself does not register as a collapse in corruption.
+    pub fn corruption_velocity(&self) -> f64 {
+        self.corruption_velocity
+    }
+
+    /// Signed change in destruction entropy over the most recent tick, perturbations included.
+    ///
+    /// Uses the same baselines as `corruption_velocity`.
+    pub fn entropy_rate(&self) -> f64 {
+        self.entropy_rate
+    }
+
+    /// Signed change in entity count over the most recent tick, relative to the count before.
+    ///
+    /// Uses the same baselines as `corruption_velocity`.
+    pub fn population_rate(&self) -> f64 {
+        self.population_rate
+    }
+
+    /// How fast the world is heading toward a black tide, in `[0.0, 1.0)`.
+    ///
+    /// The weighted mean, by `EngineConfig::momentum_weights`, of
+    /// `tanh(max(0, rate / scale))` for the entropy rate, corruption velocity, and population
+    /// rate, with the `MOMENTUM_*_SCALE` constants as scales. Falling signals contribute zero;
+    /// with every weight at zero the momentum is 0.0.
+    pub fn collapse_momentum(&self) -> f64 {
+        let weights = self.config.momentum_weights;
+        let signals = [
+            (
+                weights.entropy_rate,
+                self.entropy_rate / MOMENTUM_ENTROPY_RATE_SCALE,
+            ),
+            (
+                weights.corruption_velocity,
+                self.corruption_velocity / MOMENTUM_CORRUPTION_VELOCITY_SCALE,
+            ),
+            (
+                weights.population_rate,
+                self.population_rate / MOMENTUM_POPULATION_RATE_SCALE,
+            ),
+        ];
+        let (weighted, total_weight) =
+            signals
+                .iter()
+                .fold((0.0, 0.0), |(weighted, total_weight), &(weight, signal)| {
+                    let weight = weight.max(0.0);
+                    (
+                        weighted + weight * signal.max(0.0).tanh(),
+                        total_weight + weight,
+                    )
+                });
+        if total_weight > 0.0 {
+            weighted / total_weight
+        } else {
+            0.0
+        }
+    }
+
+    /// Spawns that failed since this engine was built, whether asked for directly, through
+    /// observer commands, or by seeding.
+    pub fn failed_spawns(&self) -> u64 {
+        self.failed_spawns
+    }
+
+    /// Number of coreflames the most recent corruption pass flipped to Destruction.
+    pub fn flips_last_tick(&self) -> u32 {
+        self.flips_last_tick
+    }
+
+    /// Entities the last corruption pass flagged under `power_clamp_policy`, in ID order.
+    ///
+    /// Always empty under `ClampPolicy::Saturate`. Under `Despawn` they are already gone.
+    pub fn clamped_last_tick(&self) -> &[Entity] {
+        &self.clamped_last_tick
+    }
+
+    /// Despawns everything queued in `pending_despawns`, leaving the queue empty.
+    fn flush_pending_despawns(&mut self) {
+        if self.pending_despawns.is_empty() {
+            return;
+        }
+        let pending = &self.pending_despawns;
+        let _ = self.world.write(|ecs| {
+            for &entity in pending {
+                let _ = ecs.despawn(entity);
             }
         });
+        self.pending_despawns.clear();
+    }
+
+    pub fn apply_golden_blood_corruption(&mut self) {
+        self.clamped_last_tick.clear();
+        if !self.config.corruption_enabled {
+            self.flips_last_tick = 0;
+            return;
+        }
+
+        let local_entropy = self.state.destruction_entropy;
+        let config = self.config;
+        let (flips, clamped) = self
+            .run_parallel(|| {
+                self.world
+                    .write(|ecs| corrupt_golden_blood(ecs, local_entropy, &config))
+            })
+            .unwrap_or_default();
+        self.flips_last_tick = flips;
+        self.clamped_last_tick = clamped;
+
+        if config.power_clamp_policy == ClampPolicy::Despawn && !self.clamped_last_tick.is_empty() {
+            let _ = self.world.write(|ecs| {
+                for &entity in &self.clamped_last_tick {
+                    let _ = ecs.despawn(entity);
+                }
+            });
+        }
+    }
+}
+
+/// Orders threats by descending score, then ascending entity ID.
+fn rank_threats(a: &(Entity, f64), b: &(Entity, f64)) -> std::cmp::Ordering {
+    b.1.total_cmp(&a.1).then(a.0.cmp(&b.0))
+}
+
+/// Raises corruption on tainted golden blood and weakens the matching coreflames.
+///
+/// Returns how many coreflames changed alignment to Destruction, and the entities flagged by
+/// the power clamp policy in ascending ID order.
+fn corrupt_golden_blood(
+    ecs: &mut SoaEcs,
+    local_entropy: f64,
+    config: &EngineConfig,
+) -> (u32, Vec<Entity>) {
+    let spread_threshold = config.corruption_spread_threshold;
+    let blood_gain = local_entropy * config.entropy_to_corruption;
+    let clamp_policy = config.power_clamp_policy;
+    let decay_rate = config.power_decay_rate;
+
+    // Purified worlds skip both passes entirely.
+    if ecs.golden_blood.is_empty() {
+        return (0, Vec::new());
+    }
+
+    // Storage order runs the parallel passes; shuffled orders, and every wasm build, run the
+    // serial per-entity pass below.
+    #[cfg(not(target_arch = "wasm32"))]
+    if !matches!(config.corruption_order, CorruptionOrder::Shuffled { .. }) {
+        // Golden blood without a coreflame only gains corruption.
+        let resistance = &ecs.corruption_resistance;
+        let coreflames = &ecs.coreflames;
+        let (golden_entities, golden_data) = ecs.golden_blood.dense_pairs_mut();
+        let blood_only: Vec<Entity> = golden_entities
+            .par_iter()
+            .copied()
+            .zip(golden_data.par_iter_mut())
+            .filter_map(|(entity, blood)| {
+                if coreflames.contains(entity) || blood.corruption_level < spread_threshold {
+                    return None;
+                }
+
+                let gain = blood_gain * resistance_factor(resistance, entity);
+                blood.corruption_level = (blood.corruption_level + gain).clamp(0.0, 1.0);
+                Some(entity)
+            })
+            .collect();
+
+        // Entities carrying both are corrupted and decayed in one step over the join.
+        let resistance = &ecs.corruption_resistance;
+        let mut joined = Vec::with_capacity(ecs.coreflames.len().min(ecs.golden_blood.len()));
+        joined.extend(ecs.coreflames.join_mut(&mut ecs.golden_blood));
+        let updates: Vec<(Entity, bool, bool)> = joined
+            .into_par_iter()
+            .filter_map(|(entity, coreflame, blood)| {
+                if blood.corruption_level < spread_threshold {
+                    return None;
+                }
+
+                let gain = blood_gain * resistance_factor(resistance, entity);
+                blood.corruption_level = (blood.corruption_level + gain).clamp(0.0, 1.0);
+                let corruption_level = blood.corruption_level;
+                if corruption_level <= 0.0 {
+                    return Some((entity, false, false));
+                }
+
+                let (power_level, flagged) =
+                    clamp_policy.decay(coreflame.power_level, corruption_level, decay_rate);
+                coreflame.power_level = power_level;
+                let flipped = coreflame.alignment != Path::Destruction;
+                coreflame.alignment = Path::Destruction;
+                Some((entity, flipped, flagged))
+            })
+            .collect();
+
+        // Watch notifications cannot be raised inside the parallel passes; queue them here.
+        for entity in blood_only {
+            ecs.mark_changed(entity, WatchMask::GOLDEN_BLOOD);
+        }
+        let mut flips = 0;
+        let mut clamped = Vec::new();
+        for (entity, flipped, flagged) in updates {
+            ecs.mark_changed(entity, WatchMask::GOLDEN_BLOOD | WatchMask::COREFLAME);
+            flips += u32::from(flipped);
+            if flagged {
+                clamped.push(entity);
+            }
+        }
+        clamped.sort_unstable();
+        return (flips, clamped);
+    }
+
+    let mut flips = 0;
+    let mut clamped = Vec::new();
+    for entity in config
+        .corruption_order
+        .arrange(ecs.golden_blood.dense_entities())
+    {
+        let (flipped, flagged) = corrupt_entity(
+            ecs,
+            entity,
+            blood_gain,
+            spread_threshold,
+            clamp_policy,
+            decay_rate,
+        );
+        flips += u32::from(flipped);
+        if flagged {
+            clamped.push(entity);
+        }
+    }
+    clamped.sort_unstable();
+    (flips, clamped)
+}
+
+/// Blood-gain multiplier for `entity`; missing components count as 1.0, negatives as 0.0.
+fn resistance_factor(store: &HashComponentStore<CorruptionResistance>, entity: Entity) -> f64 {
+    store
+        .get(entity)
+        .map_or(1.0, |resistance| resistance.factor.max(0.0))
+}
+
+/// Serial single-entity form of the corruption pass; returns whether the coreflame flipped
+/// and whether the clamp policy flagged it.
+fn corrupt_entity(
+    ecs: &mut SoaEcs,
+    entity: Entity,
+    blood_gain: f64,
+    spread_threshold: f64,
+    clamp_policy: ClampPolicy,
+    decay_rate: f64,
+) -> (bool, bool) {
+    if !ecs.is_alive(entity) {
+        return (false, false);
+    }
+    let gain = blood_gain * resistance_factor(&ecs.corruption_resistance, entity);
+    let Some(blood) = ecs.golden_blood.get_mut(entity) else {
+        return (false, false);
+    };
+    if blood.corruption_level < spread_threshold {
+        return (false, false);
+    }
+
+    blood.corruption_level = (blood.corruption_level + gain).clamp(0.0, 1.0);
+    let corruption_level = blood.corruption_level;
+
+    let mut changed = WatchMask::GOLDEN_BLOOD;
+    let mut flipped = false;
+    let mut flagged = false;
+    if let Some(coreflame) = ecs.coreflames.get_mut(entity) {
+        (coreflame.power_level, flagged) =
+            clamp_policy.decay(coreflame.power_level, corruption_level, decay_rate);
+        flipped = coreflame.alignment != Path::Destruction;
+        coreflame.alignment = Path::Destruction;
+        changed = changed | WatchMask::COREFLAME;
+    }
+    ecs.mark_changed(entity, changed);
+    (flipped, flagged)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn build_worker_pool(worker_threads: Option<usize>) -> Option<rayon::ThreadPool> {
+    let threads = worker_threads?;
+    match rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.max(1))
+        .thread_name(|idx| format!("amphoreus-worker-{idx}"))
+        .build()
+    {
+        Ok(pool) => Some(pool),
+        Err(err) => {
+            eprintln!("failed to build {threads}-thread worker pool, using global pool: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{with_global_ecs, with_global_ecs_mut};
+
+    /// A page path in the temp directory, unique to this test process.
+    fn temp_page(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("amphoreus_{name}_{}.page", std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// An engine simulating its own world, so tests never share entities.
+    fn independent_engine(arena_capacity: usize) -> AmphoreusEngine {
+        AmphoreusEngine::with_world(arena_capacity, EngineConfig::default(), EcsWorld::new(0))
+            .expect("default config is valid")
+    }
+
+    #[test]
+    fn total_power_sums_a_known_set_and_falls_after_corruption() {
+        let mut engine = independent_engine(1 << 16);
+        assert_eq!(engine.world.read(|ecs| ecs.total_power()), 0.0);
+
+        for (power_level, corruption_level) in [(0.5, 0.9), (1.25, 0.7), (2.0, 0.1)] {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    coreflame: Some(Coreflame {
+                        power_level,
+                        alignment: Path::Erudition,
+                    }),
+                    golden_blood: Some(GoldenBlood { corruption_level }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room");
+        }
+        assert_eq!(engine.world.read(|ecs| ecs.total_power()), 3.75);
+
+        // Blood at or above 0.6 rises by entropy * 0.05 and then drains power
+        // by 3% of the new level; the 0.1 entity is left alone.
+        engine.state.destruction_entropy = 0.5;
+        engine.apply_golden_blood_corruption();
+        let expected = 0.5 * (1.0 - 0.925 * 0.03) + 1.25 * (1.0 - 0.725 * 0.03) + 2.0;
+        let total = engine.world.read(|ecs| ecs.total_power());
+        assert!((total - expected).abs() < 1e-12, "{total} vs {expected}");
+        assert!(total < 3.75);
+    }
+
+    #[test]
+    fn cancelling_mid_seed_stops_spawning_and_reports_the_partial_count() {
+        let mut engine = independent_engine(1 << 28);
+        let cancel = std::sync::Arc::new(AtomicBool::new(false));
+        let canceller = {
+            let cancel = std::sync::Arc::clone(&cancel);
+            let world = engine.world.clone();
+            std::thread::spawn(move || {
+                while world.read(|ecs| ecs.entity_count()) < 10_000 {
+                    std::thread::yield_now();
+                }
+                cancel.store(true, Ordering::Relaxed);
+            })
+        };
+
+        let report = engine
+            .seed_world_cancellable(
+                WorldSeedConfig {
+                    citizens: 2_000_000,
+                    ..WorldSeedConfig::default()
+                },
+                &cancel,
+            )
+            .expect("the arena has room for every spawn");
+        canceller.join().expect("canceller finishes");
+
+        assert!(report.cancelled);
+        assert!(report.spawned >= 10_000 && report.spawned < report.requested);
+        assert_eq!(
+            engine.world.read(|ecs| ecs.entity_count()),
+            report.spawned as usize
+        );
+        assert_eq!(engine.flame_chase, FlameChaseHandles::default());
+    }
+
+    #[test]
+    fn seeding_with_the_flag_already_set_spawns_nothing() {
+        let mut engine = independent_engine(1 << 16);
+        let report = engine
+            .seed_world_cancellable(WorldSeedConfig::default(), &AtomicBool::new(true))
+            .expect("nothing was spawned");
+        assert!(report.cancelled);
+        assert_eq!(report.spawned, 0);
+        assert_eq!(engine.world.read(|ecs| ecs.entity_count()), 0);
+    }
+
+    #[test]
+    fn zero_power_policies_decide_the_fate_of_drained_coreflames() {
+        for policy in [
+            ZeroPowerPolicy::Keep,
+            ZeroPowerPolicy::Despawn,
+            ZeroPowerPolicy::Revert,
+        ] {
+            let mut engine = AmphoreusEngine::with_world(
+                1 << 16,
+                EngineConfig {
+                    zero_power_policy: policy,
+                    ..EngineConfig::default()
+                },
+                EcsWorld::new(0),
+            )
+            .expect("config is valid");
+            engine.state.destruction_entropy = 0.5;
+            let spawn = |engine: &mut AmphoreusEngine, power_level, corruption_level| {
+                engine
+                    .spawn_entity(SpawnEntitySpec {
+                        coreflame: Some(Coreflame {
+                            power_level,
+                            alignment: Path::Remembrance,
+                        }),
+                        golden_blood: Some(GoldenBlood { corruption_level }),
+                        ..SpawnEntitySpec::default()
+                    })
+                    .expect("arena has room")
+            };
+            let drained = spawn(&mut engine, 0.0, 0.9);
+            let healthy = spawn(&mut engine, 0.5, 0.1);
+            engine.apply_golden_blood_corruption();
+            engine.apply_zero_power_policy();
+
+            let (drained_flame, healthy_flame) = engine.world.read(|ecs| {
+                (
+                    ecs.coreflames.get(drained).copied(),
+                    ecs.coreflames.get(healthy).copied(),
+                )
+            });
+            let expected = match policy {
+                ZeroPowerPolicy::Keep => Some(Path::Destruction),
+                ZeroPowerPolicy::Despawn => None,
+                ZeroPowerPolicy::Revert => Some(Path::None),
+            };
+            assert_eq!(drained_flame.map(|flame| flame.alignment), expected);
+            assert!(drained_flame.is_none_or(|flame| flame.power_level == 0.0));
+            assert_eq!(
+                engine.world.read(|ecs| ecs.is_alive(drained)),
+                policy != ZeroPowerPolicy::Despawn
+            );
+            assert_eq!(
+                healthy_flame,
+                Some(Coreflame {
+                    power_level: 0.5,
+                    alignment: Path::Remembrance,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn flame_chase_snapshot_mirrors_the_tracked_pair() {
+        let mut engine = independent_engine(1 << 20);
+        engine
+            .seed_world(WorldSeedConfig {
+                citizens: 200,
+                titans: 12,
+                chrysos_heirs: 16,
+                ..WorldSeedConfig::default()
+            })
+            .expect("seed fits");
+        engine.tick();
+
+        let memory_of = |handle: Option<EntityHandle>| {
+            engine
+                .world
+                .read(|ecs| ecs.memory_logs.get(handle.unwrap().index).copied())
+                .expect("flame-chase pair has memory logs")
+        };
+        let phainon = memory_of(engine.flame_chase.phainon);
+        let cyrene = memory_of(engine.flame_chase.cyrene);
+        assert_eq!(
+            engine.flame_chase_snapshot(),
+            FlameChaseSnapshot {
+                phainon_trauma: Some(phainon.trauma_index),
+                phainon_cycles: Some(phainon.retained_cycles),
+                cyrene_trauma: Some(cyrene.trauma_index),
+                exploit_active: !engine.state.time_concept_active,
+            }
+        );
+        assert!(engine.flame_chase_snapshot().exploit_active);
+
+        engine.flame_chase = FlameChaseHandles::default();
+        let missing = engine.flame_chase_snapshot();
+        assert_eq!(
+            (
+                missing.phainon_trauma,
+                missing.phainon_cycles,
+                missing.cyrene_trauma
+            ),
+            (None, None, None)
+        );
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn corruption_results_do_not_depend_on_thread_count() {
+        let run = |worker_threads| {
+            let mut engine = AmphoreusEngine::with_world(
+                1 << 22,
+                EngineConfig {
+                    worker_threads,
+                    ..EngineConfig::default()
+                },
+                EcsWorld::new(0),
+            )
+            .expect("config is valid");
+            for idx in 0..20_000_u32 {
+                engine
+                    .spawn_entity(SpawnEntitySpec {
+                        coreflame: Some(Coreflame {
+                            power_level: 0.5 + (idx % 89) as f64 * 0.01,
+                            alignment: Path::Erudition,
+                        }),
+                        golden_blood: Some(GoldenBlood {
+                            corruption_level: (idx % 100) as f64 * 0.01,
+                        }),
+                        ..SpawnEntitySpec::default()
+                    })
+                    .expect("arena has room");
+            }
+            engine.state.destruction_entropy = 0.8;
+            for _ in 0..5 {
+                engine.apply_golden_blood_corruption();
+            }
+            engine.world.read(|ecs| {
+                (
+                    ecs.coreflames
+                        .iter()
+                        .map(|(e, c)| (e, *c))
+                        .collect::<Vec<_>>(),
+                    ecs.golden_blood
+                        .iter()
+                        .map(|(e, b)| (e, *b))
+                        .collect::<Vec<_>>(),
+                )
+            })
+        };
+
+        let expected = run(None);
+        for threads in [1, 3, 8] {
+            assert!(run(Some(threads)) == expected, "{threads} worker threads");
+        }
+    }
+
+    #[test]
+    fn purify_batch_lowers_exactly_the_listed_entities_once() {
+        let mut engine = independent_engine(1 << 16);
+        let tainted: Vec<Entity> = (0..5)
+            .map(|_| {
+                engine
+                    .spawn_entity(SpawnEntitySpec {
+                        golden_blood: Some(GoldenBlood {
+                            corruption_level: 0.7,
+                        }),
+                        ..SpawnEntitySpec::default()
+                    })
+                    .expect("arena has room")
+            })
+            .collect();
+        let bloodless = engine
+            .spawn_entity(SpawnEntitySpec::default())
+            .expect("arena has room");
+
+        let listed = [
+            tainted[0], tainted[2], tainted[2], bloodless, tainted[4], tainted[0],
+        ];
+        assert_eq!(engine.purify_batch(&listed, 0.25), 3);
+
+        let levels: Vec<f64> = engine.world.read(|ecs| {
+            tainted
+                .iter()
+                .map(|&entity| ecs.golden_blood.get(entity).unwrap().corruption_level)
+                .collect()
+        });
+        let purified = 0.7 - 0.25;
+        assert_eq!(levels, [purified, 0.7, purified, 0.7, purified]);
+        assert_eq!(engine.purify_batch(&[tainted[1]], 5.0), 1);
+        assert_eq!(
+            engine
+                .world
+                .read(|ecs| ecs.golden_blood.get(tainted[1]).copied()),
+            Some(GoldenBlood {
+                corruption_level: 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn restored_engine_keeps_a_custom_black_tide_threshold() {
+        let config = EngineConfig {
+            worker_threads: Some(2),
+            zero_power_policy: ZeroPowerPolicy::Despawn,
+            black_tide_trigger: BlackTideTrigger::Threshold(0.3),
+            ..EngineConfig::default()
+        };
+        let mut saved = AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(0))
+            .expect("config is valid");
+        saved.state.cycle_count = 41;
+        saved.state.destruction_entropy = 0.25;
+
+        let path = temp_page("custom_threshold");
+        saved.snapshot_to_eternal_page(&path);
+        let restored = AmphoreusEngine::restore_from_eternal_page(&path).expect("page reads");
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(*restored.config(), config);
+        assert_eq!(
+            restored.config().black_tide_trigger,
+            BlackTideTrigger::Threshold(0.3)
+        );
+        assert_eq!(restored.state, saved.state);
+        assert_eq!(restored.world_seed, saved.world_seed);
+    }
+
+    #[test]
+    fn corruption_pass_leaves_a_bloodless_world_untouched() {
+        let mut ecs = SoaEcs::with_capacity(0);
+        let flames = [
+            Coreflame {
+                power_level: 0.8,
+                alignment: Path::Erudition,
+            },
+            Coreflame {
+                power_level: 1.4,
+                alignment: Path::Remembrance,
+            },
+        ];
+        for coreflame in flames {
+            let entity = ecs.spawn();
+            ecs.coreflames.insert(entity, coreflame);
+        }
+
+        corrupt_golden_blood(&mut ecs, 1.0, &EngineConfig::default());
+        assert_eq!(ecs.coreflames.dense_data(), flames);
+        assert!(ecs.golden_blood.is_empty());
+    }
+
+    #[test]
+    fn ages_grow_with_each_tick_within_an_epoch() {
+        let mut engine = independent_engine(1 << 16);
+        let elder = engine
+            .spawn_entity(SpawnEntitySpec::default())
+            .expect("arena has room");
+        let age = |entity| {
+            engine
+                .world
+                .read(|ecs| ecs.age_of(entity, engine.state.cycle_count))
+        };
+        assert_eq!(age(elder), Some(0));
+
+        for _ in 0..3 {
+            assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+        }
+        let newcomer = engine
+            .spawn_entity(SpawnEntitySpec::default())
+            .expect("arena has room");
+        for _ in 0..2 {
+            engine.tick();
+        }
+
+        let age = |entity| {
+            engine
+                .world
+                .read(|ecs| ecs.age_of(entity, engine.state.cycle_count))
+        };
+        assert_eq!((age(elder), age(newcomer)), (Some(5), Some(2)));
+        let _ = engine.world.write(|ecs| ecs.despawn(elder));
+        assert_eq!(age(elder), None);
+    }
+
+    /// Every entity bleeds at a spread of levels; three in four carry a coreflame.
+    fn corruption_world(n: u32) -> SoaEcs {
+        let mut ecs = SoaEcs::with_capacity(n as usize);
+        for i in 0..n {
+            let entity = ecs.spawn();
+            ecs.golden_blood.insert(
+                entity,
+                GoldenBlood {
+                    corruption_level: f64::from(i % 100) / 99.0,
+                },
+            );
+            if i % 4 != 0 {
+                ecs.coreflames.insert(
+                    entity,
+                    Coreflame {
+                        power_level: f64::from(i % 7) + 0.5,
+                        alignment: Path::Erudition,
+                    },
+                );
+            }
+        }
+        ecs
+    }
+
+    #[test]
+    fn shuffled_order_ignores_despawn_history() {
+        let order = CorruptionOrder::Shuffled { seed: 449 };
+        let config = EngineConfig {
+            corruption_order: order,
+            ..EngineConfig::default()
+        };
+        let mut fresh = corruption_world(2_000);
+        let mut churned = corruption_world(2_000);
+        // Swap-removing and reinserting every third blood leaves the same contents in a
+        // different dense order.
+        for entity in (0..2_000).step_by(3) {
+            let blood = churned
+                .golden_blood
+                .remove(entity)
+                .expect("every entity bleeds");
+            churned.golden_blood.insert(entity, blood);
+        }
+        assert_ne!(
+            fresh.golden_blood.dense_entities(),
+            churned.golden_blood.dense_entities()
+        );
+
+        let visit = order.arrange(fresh.golden_blood.dense_entities());
+        assert_eq!(visit, order.arrange(churned.golden_blood.dense_entities()));
+        assert_ne!(
+            visit,
+            CorruptionOrder::Shuffled { seed: 450 }.arrange(fresh.golden_blood.dense_entities())
+        );
+
+        for local_entropy in [0.4, 0.9] {
+            corrupt_golden_blood(&mut fresh, local_entropy, &config);
+            corrupt_golden_blood(&mut churned, local_entropy, &config);
+        }
+        let by_entity = |ecs: &SoaEcs| {
+            let mut contents: Vec<(Entity, GoldenBlood, Option<Coreflame>)> = ecs
+                .golden_blood
+                .iter()
+                .map(|(entity, &blood)| (entity, blood, ecs.coreflames.get(entity).copied()))
+                .collect();
+            contents.sort_unstable_by_key(|&(entity, _, _)| entity);
+            contents
+        };
+        assert_eq!(by_entity(&fresh), by_entity(&churned));
+    }
+
+    #[test]
+    fn entities_near_flip_lists_the_projected_band_and_skips_flipped_ones() {
+        let mut engine = independent_engine(1 << 16);
+        let mut spawn = |corruption_level, alignment: Option<Path>| {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    coreflame: alignment.map(|alignment| Coreflame {
+                        power_level: 1.0,
+                        alignment,
+                    }),
+                    golden_blood: Some(GoldenBlood { corruption_level }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room")
+        };
+        let calm = spawn(0.50, Some(Path::Erudition));
+        let pushed = spawn(0.54, Some(Path::Erudition));
+        let close = spawn(0.57, Some(Path::Remembrance));
+        let over = spawn(0.62, Some(Path::Erudition));
+        let flipped = spawn(0.59, Some(Path::Destruction));
+        let flameless = spawn(0.57, None);
+
+        // Without entropy the pass adds nothing, so only the raw band counts.
+        assert_eq!(engine.entities_near_flip(0.05), [close, over]);
+
+        // At 0.4 entropy the pass adds 0.02, pulling 0.54 into the 0.55 band edge.
+        engine.state.destruction_entropy = 0.4;
+        assert_eq!(engine.entities_near_flip(0.05), [pushed, close, over]);
+        assert_eq!(engine.entities_near_flip(0.0), [over]);
+        let wide = engine.entities_near_flip(0.2);
+        assert!(wide.contains(&calm));
+        assert!(!wide.contains(&flipped) && !wide.contains(&flameless));
+    }
+
+    /// Saves `engine` with `compression` and reads it straight back.
+    fn page_round_trip(
+        engine: &mut AmphoreusEngine,
+        compression: PageCompression,
+        name: &str,
+    ) -> (AmphoreusEngine, u64) {
+        engine
+            .set_config(EngineConfig {
+                page_compression: compression,
+                ..*engine.config()
+            })
+            .expect("config is valid");
+        let path = temp_page(name);
+        engine.snapshot_to_eternal_page(&path);
+        let size = fs::metadata(&path).expect("page was written").len();
+        let restored = AmphoreusEngine::restore_from_eternal_page(&path).expect("page reads");
+        let _ = fs::remove_file(&path);
+        (restored, size)
+    }
+
+    #[test]
+    fn uncompressed_pages_round_trip_through_the_header() {
+        let mut saved = independent_engine(1 << 12);
+        saved.arena.alloc_bytes(96, 8).expect("arena has room");
+        saved.state.cycle_count = 7;
+        let (restored, _) = page_round_trip(&mut saved, PageCompression::None, "plain");
+        assert_eq!(restored.arena.used_bytes(), saved.arena.used_bytes());
+        assert_eq!(restored.state, saved.state);
+
+        let path = temp_page("headerless");
+        fs::write(&path, b"not a page").expect("temp dir is writable");
+        let result = AmphoreusEngine::restore_from_eternal_page(&path);
+        let _ = fs::remove_file(&path);
+        assert!(matches!(result, Err(RestoreError::NotAPage)));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn compressed_page_restores_the_same_engine_as_an_uncompressed_one() {
+        let mut saved = independent_engine(1 << 16);
+        let bytes = saved
+            .arena
+            .alloc_bytes(32 * 1024, 8)
+            .expect("arena has room");
+        for (idx, byte) in bytes.iter_mut().enumerate() {
+            *byte = (idx % 13) as u8;
+        }
+        saved.state.cycle_count = 12;
+        saved.state.destruction_entropy = 0.4;
+
+        let (plain, plain_size) = page_round_trip(&mut saved, PageCompression::None, "zstd_plain");
+        let (packed, packed_size) =
+            page_round_trip(&mut saved, PageCompression::Zstd(3), "zstd_packed");
+
+        assert!(packed_size < plain_size);
+        assert_eq!(packed.arena.used_bytes(), plain.arena.used_bytes());
+        assert_eq!(packed.arena.offset, plain.arena.offset);
+        assert_eq!(packed.state, plain.state);
+        assert_eq!(packed.world_seed, plain.world_seed);
+        assert_eq!(
+            EngineConfig {
+                page_compression: PageCompression::None,
+                ..*packed.config()
+            },
+            *plain.config()
+        );
+    }
+
+    #[test]
+    fn corruption_pass_notifies_watchers_once_after_the_flush() {
+        for order in [
+            CorruptionOrder::Storage,
+            CorruptionOrder::Shuffled { seed: 452 },
+        ] {
+            let mut ecs = SoaEcs::with_capacity(0);
+            let entity = ecs.spawn();
+            ecs.golden_blood.insert(
+                entity,
+                GoldenBlood {
+                    corruption_level: 0.9,
+                },
+            );
+            ecs.coreflames.insert(
+                entity,
+                Coreflame {
+                    power_level: 1.0,
+                    alignment: Path::Erudition,
+                },
+            );
+            let events = std::sync::Arc::new(parking_lot::Mutex::new(Vec::new()));
+            let sink = std::sync::Arc::clone(&events);
+            ecs.watch(entity, WatchMask::ALL, move |entity, changed| {
+                sink.lock().push((entity, changed));
+            });
+
+            let config = EngineConfig {
+                corruption_order: order,
+                ..EngineConfig::default()
+            };
+            corrupt_golden_blood(&mut ecs, 1.0, &config);
+            assert!(events.lock().is_empty());
+            ecs.flush_watch_events();
+            assert_eq!(
+                *events.lock(),
+                [(entity, WatchMask::GOLDEN_BLOOD | WatchMask::COREFLAME)]
+            );
+        }
+    }
+
+    #[test]
+    fn flame_chase_pair_spawns_in_an_arena_just_large_enough() {
+        let seed = WorldSeedConfig {
+            citizens: 500,
+            titans: 20,
+            chrysos_heirs: 10,
+            ..WorldSeedConfig::default()
+        };
+        let mut measuring = independent_engine(1 << 20);
+        let expected = measuring.seed_world(seed).expect("seed fits");
+        assert_eq!(expected.spawned, expected.requested);
+        let needed = measuring.arena.offset;
+        let pair_alive = |engine: &AmphoreusEngine| {
+            let FlameChaseHandles { phainon, cyrene } = engine.flame_chase;
+            engine.world.read(|ecs| {
+                [phainon, cyrene]
+                    .iter()
+                    .all(|handle| handle.is_some_and(|handle| ecs.is_handle_alive(handle)))
+            })
+        };
+
+        let mut exact = independent_engine(needed);
+        assert_eq!(exact.seed_world(seed), Ok(expected));
+        assert!(pair_alive(&exact));
+
+        // Twice the citizens overflow the arena, but not into the pair's reserved room.
+        let mut overfull = independent_engine(needed);
+        let err = overfull
+            .seed_world(WorldSeedConfig {
+                citizens: seed.citizens * 2,
+                ..seed
+            })
+            .unwrap_err();
+        assert_eq!(err.cause, SpawnError::ArenaExhausted);
+        assert!(err.report.spawned < err.report.requested);
+        assert!(pair_alive(&overfull));
+    }
+
+    #[test]
+    fn black_tide_appends_a_ledger_record_that_survives_the_page() {
+        let config = EngineConfig {
+            black_tide_trigger: BlackTideTrigger::Threshold(0.0),
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(0))
+            .expect("config is valid");
+        engine.world_seed = WorldSeedConfig {
+            citizens: 0,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        engine.state.cycle_count = 7;
+        for corruption_level in [0.1, 0.2, 0.4, 0.5] {
+            let _ = engine.spawn_entity(SpawnEntitySpec {
+                coreflame: Some(Coreflame {
+                    power_level: 1.0,
+                    alignment: Path::Erudition,
+                }),
+                memory_log: None,
+                golden_blood: Some(GoldenBlood { corruption_level }),
+                cohort: None,
+                corruption_resistance: None,
+                faction: None,
+                lifespan: None,
+            });
+        }
+        let average = engine.world.read(|ecs| ecs.average_corruption());
+
+        assert_eq!(engine.tick(), SimulationResult::BlackTideTriggered);
+        assert_eq!(
+            engine.black_tides(),
+            [BlackTideRecord {
+                cycle: 7,
+                entropy: engine.state.destruction_entropy,
+                entity_count: 4,
+                average_corruption: average,
+                cohorts: CohortReport::default(),
+            }]
+        );
+
+        let path = temp_page("black_tide_ledger");
+        engine.snapshot_to_eternal_page(&path);
+        let restored = AmphoreusEngine::restore_from_eternal_page(&path).expect("page reads");
+        let _ = fs::remove_file(&path);
+        assert_eq!(restored.black_tides(), engine.black_tides());
+
+        engine
+            .set_config(EngineConfig {
+                black_tide_history_limit: 1,
+                ..config
+            })
+            .expect("config is valid");
+        assert_eq!(engine.tick(), SimulationResult::BlackTideTriggered);
+        assert_eq!(engine.black_tides().len(), 1);
+        assert_eq!(engine.black_tides()[0].cycle, 8);
+    }
+
+    #[test]
+    fn threat_subset_exports_the_top_entities_with_their_components() {
+        let mut engine = independent_engine(1 << 16);
+        let specs = [
+            // power, alignment, corruption: threats 0.6, 4.5, 2.25, none, 2.25, 0.1
+            (0.5, Path::Erudition, Some(0.2)),
+            (2.0, Path::Destruction, Some(0.5)),
+            (1.5, Path::Destruction, None),
+            (0.0, Path::None, Some(0.9)),
+            (1.5, Path::Remembrance, Some(0.5)),
+            (0.1, Path::Erudition, None),
+        ];
+        let entities: Vec<Entity> = specs
+            .iter()
+            .map(|&(power_level, alignment, corruption)| {
+                engine
+                    .spawn_entity(SpawnEntitySpec {
+                        coreflame: (power_level > 0.0).then_some(Coreflame {
+                            power_level,
+                            alignment,
+                        }),
+                        memory_log: Some(MemoryLog {
+                            trauma_index: power_level / 4.0,
+                            retained_cycles: 3,
+                        }),
+                        golden_blood: corruption
+                            .map(|corruption_level| GoldenBlood { corruption_level }),
+                        cohort: None,
+                        corruption_resistance: None,
+                        faction: None,
+                        lifespan: None,
+                    })
+                    .expect("arena has room")
+            })
+            .collect();
+
+        let subset = engine.export_threat_subset(3);
+        let expected = [entities[1], entities[2], entities[4]];
+        assert_eq!(subset.entities, expected);
+        let full = engine.world.read(|ecs| ecs.snapshot());
+        let only_expected = |pairs: &[(Entity, _)]| -> Vec<_> {
+            pairs
+                .iter()
+                .filter(|(entity, _)| expected.contains(entity))
+                .copied()
+                .collect()
+        };
+        let mut coreflames = subset.coreflames.clone();
+        coreflames.sort_by_key(|(entity, _)| *entity);
+        assert_eq!(coreflames, only_expected(&full.coreflames));
+        assert_eq!(subset.memory_logs.len(), 3);
+        assert!(subset.memory_logs.contains(&(
+            entities[1],
+            MemoryLog {
+                trauma_index: 0.5,
+                retained_cycles: 3,
+            }
+        )));
+        assert_eq!(
+            subset.golden_blood,
+            [
+                (
+                    entities[1],
+                    GoldenBlood {
+                        corruption_level: 0.5
+                    }
+                ),
+                (
+                    entities[4],
+                    GoldenBlood {
+                        corruption_level: 0.5
+                    }
+                ),
+            ]
+        );
+
+        assert_eq!(engine.export_threat_subset(0), EcsSnapshot::default());
+        assert_eq!(engine.export_threat_subset(99).entities.len(), 5);
+    }
+
+    #[test]
+    fn pass_order_must_schedule_every_pass() {
+        let mut pass_order = EnginePass::DEFAULT_ORDER;
+        pass_order[2] = EnginePass::Entropy;
+        let config = EngineConfig {
+            pass_order,
+            ..EngineConfig::default()
+        };
+        let missing = Err(ConfigError::MissingPass(EnginePass::PhainonMemory));
+        assert_eq!(config.validate(), missing);
+        assert!(AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(0)).is_err());
+        assert_eq!(independent_engine(1 << 16).set_config(config), missing);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn corruption_before_entropy_climbs_faster_once_switched_mid_run() {
+        const REORDERED: [EnginePass; 5] = [
+            EnginePass::TimeExploit,
+            EnginePass::Corruption,
+            EnginePass::Entropy,
+            EnginePass::PhainonMemory,
+            EnginePass::MemoryAging,
+        ];
+        let trajectory = |switch_after: Option<usize>| {
+            let config = EngineConfig {
+                black_tide_trigger: BlackTideTrigger::Threshold(2.0),
+                ..EngineConfig::default()
+            };
+            let mut engine = AmphoreusEngine::with_world(
+                1 << 16,
+                config,
+                EcsWorld::from_ecs(corruption_world(5_000)),
+            )
+            .expect("config is valid");
+            (0..12)
+                .map(|tick| {
+                    if switch_after == Some(tick) {
+                        let reordered = EngineConfig {
+                            pass_order: REORDERED,
+                            ..config
+                        };
+                        engine
+                            .set_config(reordered)
+                            .expect("every pass is scheduled");
+                    }
+                    assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+                    engine.state.destruction_entropy
+                })
+                .collect::<Vec<f64>>()
+        };
+
+        let default = trajectory(None);
+        // From zero entropy the first reordered corruption pass gains nothing, which lines
+        // the two orders up exactly.
+        assert_eq!(trajectory(Some(0)), default);
+
+        let switched = trajectory(Some(4));
+        assert_eq!(switched[..4], default[..4]);
+        assert!(switched[4..].iter().zip(&default[4..]).all(|(a, b)| a > b));
+    }
+
+    #[test]
+    fn near_empty_worlds_tick_with_finite_entropy() {
+        let ticks_finitely = |engine: &mut AmphoreusEngine| {
+            for _ in 0..50 {
+                assert_ne!(engine.tick(), SimulationResult::BlackTideTriggered);
+                let entropy = engine.state.destruction_entropy;
+                assert!((0.0..=1.0).contains(&entropy), "entropy {entropy}");
+            }
+        };
+
+        let mut pair_only = independent_engine(1 << 16);
+        let report = pair_only
+            .seed_world(WorldSeedConfig {
+                citizens: 0,
+                titans: 0,
+                chrysos_heirs: 0,
+                ..WorldSeedConfig::default()
+            })
+            .expect("pair fits");
+        assert_eq!(report.spawned, 2);
+        assert_eq!(pair_only.world.read(|ecs| ecs.entity_count()), 2);
+        ticks_finitely(&mut pair_only);
+
+        let _ = pair_only.world.write(|ecs| ecs.clear_for_black_tide());
+        ticks_finitely(&mut independent_engine(1 << 16));
+    }
+
+    #[test]
+    fn large_perturbation_triggers_a_black_tide_and_small_does_not() {
+        let mut engine = independent_engine(1 << 16);
+        engine.world_seed = WorldSeedConfig {
+            citizens: 0,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        engine.perturb_entropy(0.2);
+        assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+        let nudged = engine.state.destruction_entropy;
+        assert!(nudged >= 0.2);
+        assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+        assert!(engine.state.destruction_entropy < nudged);
+
+        engine.perturb_entropy(1.5);
+        assert_eq!(engine.tick(), SimulationResult::BlackTideTriggered);
+        assert_eq!(engine.black_tides().len(), 1);
+        assert_eq!(engine.black_tides()[0].entropy, 1.0);
+    }
+
+    #[test]
+    fn flip_count_matches_the_alignments_that_changed() {
+        for order in [
+            CorruptionOrder::Storage,
+            CorruptionOrder::Shuffled { seed: 464 },
+        ] {
+            let mut ecs = corruption_world(4_000);
+            // Some tainted entities already serve Destruction and must not count again.
+            for entity in (0..4_000).step_by(9) {
+                if let Some(coreflame) = ecs.coreflames.get_mut(entity) {
+                    coreflame.alignment = Path::Destruction;
+                }
+            }
+            let before: Vec<(Entity, Path)> = ecs
+                .coreflames
+                .iter()
+                .map(|(entity, coreflame)| (entity, coreflame.alignment))
+                .collect();
+
+            let config = EngineConfig {
+                corruption_order: order,
+                ..EngineConfig::default()
+            };
+            let (flips, _) = corrupt_golden_blood(&mut ecs, 0.5, &config);
+            let changed = before
+                .iter()
+                .filter(|&&(entity, alignment)| {
+                    ecs.coreflames
+                        .get(entity)
+                        .map(|coreflame| coreflame.alignment)
+                        != Some(alignment)
+                })
+                .count();
+            assert!(changed > 0);
+            assert_eq!(flips as usize, changed, "{order:?}");
+        }
+    }
+
+    #[test]
+    fn strong_feedback_collapses_where_weak_feedback_settles() {
+        // Ticks until the first black tide, and the entropy trajectory leading up to it.
+        let run = |entropy_to_corruption: f64, corruption_to_entropy: f64| {
+            let config = EngineConfig {
+                entropy_to_corruption,
+                corruption_to_entropy,
+                ..EngineConfig::default()
+            };
+            let mut engine = AmphoreusEngine::with_world(
+                1 << 16,
+                config,
+                EcsWorld::from_ecs(corruption_world(5_000)),
+            )
+            .expect("config is valid");
+            engine.world_seed = WorldSeedConfig {
+                citizens: 0,
+                titans: 0,
+                chrysos_heirs: 0,
+                ..WorldSeedConfig::default()
+            };
+            let mut trajectory = Vec::new();
+            for tick in 0..200 {
+                let result = engine.tick();
+                trajectory.push(engine.state.destruction_entropy);
+                if result == SimulationResult::BlackTideTriggered {
+                    return (Some(tick), trajectory);
+                }
+            }
+            (None, trajectory)
+        };
+
+        let (weak_tide, weak) = run(0.01, 0.1);
+        let (strong_tide, strong) = run(0.5, 4.0);
+        assert_eq!(weak_tide, None);
+        let settled = &weak[weak.len() - 20..];
+        assert!(settled.iter().all(|&entropy| entropy == settled[0]));
+        assert!(settled[0] < 0.5);
+
+        let collapse = strong_tide.expect("strong feedback reaches a black tide");
+        assert!(collapse < 20, "collapsed after {collapse} ticks");
+        assert!(strong.windows(2).all(|pair| pair[1] > pair[0]));
+    }
+
+    #[test]
+    fn memory_aging_counts_survived_ticks_and_restarts_for_the_respawned() {
+        let mut engine = independent_engine(1 << 16);
+        let spawn_citizen = |engine: &mut AmphoreusEngine| {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    memory_log: Some(MemoryLog {
+                        retained_cycles: 0,
+                        trauma_index: 0.3,
+                    }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room")
+        };
+        let world = engine.world.clone();
+        let retained =
+            |entity| world.read(|ecs| ecs.memory_logs.get(entity).map(|log| log.retained_cycles));
+        let survivor = spawn_citizen(&mut engine);
+        let culled = spawn_citizen(&mut engine);
+
+        for _ in 0..3 {
+            assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+        }
+        assert_eq!(retained(survivor), Some(3));
+        assert_eq!(retained(culled), Some(3));
+
+        let _ = engine.world.write(|ecs| ecs.despawn(culled));
+        let respawned = spawn_citizen(&mut engine);
+        assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+        assert_eq!(respawned, culled, "the freed ID is recycled");
+        assert_eq!(retained(survivor), Some(4));
+        assert_eq!(retained(respawned), Some(1));
+    }
+
+    #[test]
+    fn disabled_corruption_freezes_the_world_but_not_entropy() {
+        let config = EngineConfig {
+            corruption_enabled: false,
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            config,
+            EcsWorld::from_ecs(corruption_world(2_000)),
+        )
+        .expect("config is valid");
+        let world = engine.world.clone();
+        let components = || {
+            world.read(|ecs| {
+                (
+                    ecs.coreflames.dense_data().to_vec(),
+                    ecs.golden_blood.dense_data().to_vec(),
+                )
+            })
+        };
+        let frozen = components();
+
+        for _ in 0..5 {
+            let expected =
+                evaluate_destruction_ast_with(&engine.build_destruction_nodes(), &config.eval);
+            assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+            assert_eq!(engine.state.destruction_entropy, expected);
+            assert!(expected > 0.0);
+            assert_eq!(engine.flips_last_tick(), 0);
+        }
+        assert_eq!(components(), frozen);
+    }
+
+    #[test]
+    fn freeze_all_leaves_a_bypassed_world_untouched() {
+        // Whether the world, and separately the entropy, survive five bypassed ticks intact.
+        let unchanged = |policy| {
+            let config = EngineConfig {
+                time_bypass_policy: policy,
+                ..EngineConfig::default()
+            };
+            let mut engine = AmphoreusEngine::with_world(1 << 20, config, EcsWorld::new(0))
+                .expect("config is valid");
+            let _ = engine.seed_world(WorldSeedConfig {
+                citizens: 500,
+                titans: 20,
+                chrysos_heirs: 10,
+                ..WorldSeedConfig::default()
+            });
+            assert!(!engine.state.time_concept_active);
+
+            let observe = |engine: &AmphoreusEngine| {
+                let world = engine.world.read(|ecs| ecs.snapshot());
+                (world, engine.persistent_memories[PHAINON_MEMORY].log)
+            };
+            let before = (observe(&engine), engine.state);
+            for _ in 0..5 {
+                assert_eq!(engine.tick(), SimulationResult::TimeBypassed);
+            }
+            (before.0 == observe(&engine), before.1 == engine.state)
+        };
+
+        assert_eq!(unchanged(TimeBypassPolicy::FreezeAll), (true, true));
+        assert_eq!(unchanged(TimeBypassPolicy::EntropyOnly), (true, false));
+        assert_eq!(unchanged(TimeBypassPolicy::Current), (false, false));
+    }
+
+    #[test]
+    fn black_tide_reports_flips_and_zero_power_per_cohort() {
+        let config = EngineConfig {
+            black_tide_trigger: BlackTideTrigger::Threshold(0.0),
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(0))
+            .expect("config is valid");
+        engine.world_seed = WorldSeedConfig {
+            citizens: 0,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        let members = [
+            (Cohort::Citizen, 1.0, Path::Destruction),
+            (Cohort::Citizen, 0.0, Path::Erudition),
+            (Cohort::Citizen, 1.0, Path::Erudition),
+            (Cohort::Titan, 0.0, Path::Destruction),
+            (Cohort::Titan, 2.0, Path::Destruction),
+        ];
+        for (cohort, power_level, alignment) in members {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    coreflame: Some(Coreflame {
+                        power_level,
+                        alignment,
+                    }),
+                    cohort: Some(cohort),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room");
+        }
+        engine
+            .spawn_entity(SpawnEntitySpec {
+                coreflame: Some(Coreflame {
+                    power_level: 0.0,
+                    alignment: Path::Destruction,
+                }),
+                ..SpawnEntitySpec::default()
+            })
+            .expect("arena has room");
+
+        assert_eq!(engine.tick(), SimulationResult::BlackTideTriggered);
+        assert_eq!(
+            engine.black_tides()[0].cohorts,
+            CohortReport {
+                citizens: CohortStats {
+                    population: 3,
+                    destruction: 1,
+                    zero_power: 1,
+                },
+                titans: CohortStats {
+                    population: 2,
+                    destruction: 2,
+                    zero_power: 1,
+                },
+                chrysos_heirs: CohortStats::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn retained_cycles_raise_entropy_at_equal_trauma() {
+        let first_tick_entropy = |retained_cycles| {
+            let config = EngineConfig {
+                retained_cycle_weight: 0.5,
+                retained_cycle_saturation: 10.0,
+                ..EngineConfig::default()
+            };
+            let mut engine = AmphoreusEngine::with_world(
+                1 << 16,
+                config,
+                EcsWorld::from_ecs(corruption_world(500)),
+            )
+            .expect("config is valid");
+            engine.persistent_memories[PHAINON_MEMORY].log = MemoryLog {
+                trauma_index: 0.4,
+                retained_cycles,
+            };
+            assert_ne!(engine.tick(), SimulationResult::BlackTideTriggered);
+            engine.state.destruction_entropy
+        };
+
+        let fresh = first_tick_entropy(0);
+        let veteran = first_tick_entropy(1_000);
+        assert!(veteran > fresh, "{veteran} <= {fresh}");
+        // Saturating: a thousandfold longer memory adds far less than the first thousand cycles.
+        let ancient = first_tick_entropy(1_000_000);
+        assert!(ancient >= veteran && ancient - veteran < veteran - fresh);
+    }
+
+    #[test]
+    fn forced_black_tide_carries_phainon_memory_and_advances_the_counts() {
+        let mut engine = independent_engine(1 << 16);
+        engine.world_seed = WorldSeedConfig {
+            citizens: 4,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        engine.force_black_tide();
+        let phainon = engine.flame_chase.phainon.expect("reseeded Phainon");
+        let scarred = MemoryLog {
+            trauma_index: 0.8,
+            retained_cycles: 41,
+        };
+        let _ = engine
+            .world
+            .write(|ecs| ecs.memory_logs.insert(phainon.index, scarred));
+        assert!(engine.state.destruction_entropy < 1.0);
+
+        engine.force_black_tide();
+        assert_eq!(engine.state.cycle_count, 2);
+        assert_eq!(engine.black_tides().len(), 2);
+        assert_eq!(engine.black_tides()[1].cycle, 1);
+        assert_eq!(engine.black_tides()[1].entity_count, 6);
+        assert_eq!(engine.persistent_memories[PHAINON_MEMORY].log, scarred);
+
+        let reborn = engine.flame_chase.phainon.expect("reseeded Phainon");
+        let memory = engine
+            .world
+            .read(|ecs| ecs.memory_logs.get(reborn.index).copied());
+        assert_eq!(memory, Some(scarred));
+        assert_eq!(engine.world.read(SoaEcs::entity_count), 6);
+    }
+
+    #[test]
+    fn entropy_delta_matches_an_actual_spawn_then_evaluate() {
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            EngineConfig::default(),
+            EcsWorld::from_ecs(corruption_world(200)),
+        )
+        .expect("default config is valid");
+        let evaluate = |engine: &AmphoreusEngine| {
+            evaluate_destruction_ast_with(&engine.build_destruction_nodes(), &engine.config.eval)
+        };
+
+        let corrupt = GoldenBlood {
+            corruption_level: 0.95,
+        };
+        for golden_blood in [Some(corrupt), None] {
+            let spec = SpawnEntitySpec {
+                coreflame: Some(Coreflame {
+                    power_level: 3.0,
+                    alignment: Path::Destruction,
+                }),
+                golden_blood,
+                ..SpawnEntitySpec::default()
+            };
+            let before = evaluate(&engine);
+            let count = engine.world.read(SoaEcs::entity_count);
+            let delta = engine.entropy_delta_if_spawned(&spec);
+            assert_eq!(evaluate(&engine), before);
+            assert_eq!(engine.world.read(SoaEcs::entity_count), count);
+
+            engine.spawn_entity(spec).expect("arena has room");
+            let actual = evaluate(&engine) - before;
+            assert!((delta - actual).abs() < 1e-12, "{delta} vs {actual}");
+            assert_ne!(delta, 0.0);
+        }
+    }
+
+    #[test]
+    fn autosaves_rotate_and_restore_picks_the_newest_valid_cycle() {
+        let prefix = std::env::temp_dir().join(format!("amphoreus_ring_{}", std::process::id()));
+        let slot = |n| autosave_path(&prefix, n);
+        let clean_up = || {
+            for n in 0..4 {
+                let _ = fs::remove_file(slot(n));
+            }
+        };
+        clean_up();
+        let config = EngineConfig {
+            autosave_ring_size: 3,
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(0))
+            .expect("config is valid");
+        assert_eq!(engine.autosave_prefix(), None);
+        engine.world_seed = WorldSeedConfig {
+            citizens: 0,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        engine.set_autosave_prefix(Some(prefix.clone()));
+
+        let mut written = Vec::new();
+        for _ in 0..4 {
+            engine.force_black_tide();
+            written.push((0..4).filter(|&n| slot(n).exists()).count());
+        }
+        assert_eq!(written, [1, 2, 3, 3]);
+        assert!(!slot(3).exists());
+
+        // Slot 0 was overwritten by cycle 3, after slots 1 and 2.
+        let newest = AmphoreusEngine::restore_latest_valid(&prefix, 3).expect("ring reads");
+        assert_eq!(newest.state.cycle_count, 3);
+        assert_eq!(newest.autosave_prefix(), Some(prefix.as_path()));
+        let narrowed = AmphoreusEngine::restore_latest_valid(&prefix, 2).expect("ring reads");
+        assert_eq!(narrowed.state.cycle_count, 3);
+
+        let mut bytes = fs::read(slot(0)).expect("slot 0 exists");
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 0xff;
+        fs::write(slot(0), bytes).expect("slot 0 is writable");
+        assert!(matches!(
+            page::read_page(slot(0)),
+            Err(RestoreError::ChecksumMismatch)
+        ));
+        let fallback = AmphoreusEngine::restore_latest_valid(&prefix, 3).expect("slot 2 reads");
+        assert_eq!(fallback.state.cycle_count, 2);
+        let only_corrupt = AmphoreusEngine::restore_latest_valid(&prefix, 1);
+        assert!(matches!(only_corrupt, Err(RestoreError::ChecksumMismatch)));
+
+        clean_up();
+        assert!(matches!(
+            AmphoreusEngine::restore_latest_valid(&prefix, 3),
+            Err(RestoreError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound
+        ));
+    }
+
+    #[test]
+    fn resistant_blood_corrupts_more_slowly_under_the_same_entropy() {
+        for corruption_order in [
+            CorruptionOrder::Storage,
+            CorruptionOrder::Shuffled { seed: 3 },
+        ] {
+            let config = EngineConfig {
+                corruption_order,
+                ..EngineConfig::default()
+            };
+            let mut ecs = SoaEcs::with_capacity(4);
+            let resistant = ecs.spawn();
+            let ordinary = ecs.spawn();
+            for entity in [resistant, ordinary] {
+                ecs.golden_blood.insert(
+                    entity,
+                    GoldenBlood {
+                        corruption_level: 0.65,
+                    },
+                );
+            }
+            ecs.corruption_resistance
+                .insert(resistant, CorruptionResistance { factor: 0.2 });
+
+            for _ in 0..5 {
+                corrupt_golden_blood(&mut ecs, 1.0, &config);
+            }
+            let level = |entity| ecs.golden_blood.get(entity).map(|b| b.corruption_level);
+            let resistant_level = level(resistant).expect("still has blood");
+            let ordinary_level = level(ordinary).expect("still has blood");
+            assert!((ordinary_level - 0.9).abs() < 1e-9, "{ordinary_level}");
+            assert!((resistant_level - 0.7).abs() < 1e-9, "{resistant_level}");
+        }
+
+        // The flip projection scales the gain the same way.
+        let engine_config = EngineConfig {
+            entropy_to_corruption: 0.2,
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(1 << 16, engine_config, EcsWorld::new(4))
+            .expect("config is valid");
+        let _ = engine.world.write(|ecs| {
+            for factor in [0.1, 1.0] {
+                let entity = ecs.spawn();
+                ecs.coreflames.insert(
+                    entity,
+                    Coreflame {
+                        power_level: 1.0,
+                        alignment: Path::Erudition,
+                    },
+                );
+                ecs.golden_blood.insert(
+                    entity,
+                    GoldenBlood {
+                        corruption_level: 0.45,
+                    },
+                );
+                ecs.corruption_resistance
+                    .insert(entity, CorruptionResistance { factor });
+            }
+        });
+        engine.state.destruction_entropy = 1.0;
+        assert_eq!(engine.entities_near_flip(0.0), vec![1]);
+    }
+
+    #[test]
+    fn crossings_follow_an_oscillating_entropy_trajectory() {
+        use CrossingDirection::{Downward, Upward};
+        let trajectory = [0.0, 0.3, 0.6, 0.2, 0.8, 0.5, 0.5, 0.75];
+        let watermarks = [0.75, 0.25, 0.5, f64::NAN];
+        let mut log = Vec::new();
+        for (tick, pair) in trajectory.windows(2).enumerate() {
+            record_entropy_crossings(&mut log, tick as u64, pair[0], pair[1], &watermarks);
+        }
+        let events: Vec<(u64, f64, CrossingDirection)> = log
+            .iter()
+            .map(|event| (event.tick, event.threshold, event.direction))
+            .collect();
+        assert_eq!(
+            events,
+            [
+                (0, 0.25, Upward),
+                (1, 0.5, Upward),
+                (2, 0.5, Downward),
+                (2, 0.25, Downward),
+                (3, 0.25, Upward),
+                (3, 0.5, Upward),
+                (3, 0.75, Upward),
+                (4, 0.75, Downward),
+                (6, 0.75, Upward),
+            ]
+        );
+
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            EngineConfig::default(),
+            EcsWorld::from_ecs(corruption_world(500)),
+        )
+        .expect("default config is valid");
+        let crossings = engine.run_fixed(3, &[1e-6]);
+        assert_eq!(
+            crossings,
+            [EntropyCrossing {
+                tick: 0,
+                threshold: 1e-6,
+                direction: Upward,
+            }]
+        );
+    }
+
+    #[test]
+    fn despawned_entity_is_not_corrupted_by_the_next_pass() {
+        for corruption_order in [
+            CorruptionOrder::Storage,
+            CorruptionOrder::Shuffled { seed: 3 },
+        ] {
+            let config = EngineConfig {
+                corruption_order,
+                ..EngineConfig::default()
+            };
+            let mut engine = AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(8))
+                .expect("config is valid");
+            let untouched = Coreflame {
+                power_level: 1.0,
+                alignment: Path::Remembrance,
+            };
+            let corrupted = engine
+                .spawn_entity(SpawnEntitySpec {
+                    coreflame: Some(untouched),
+                    golden_blood: Some(GoldenBlood {
+                        corruption_level: 0.9,
+                    }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room");
+            assert_eq!(engine.world.write(|ecs| ecs.despawn(corrupted)), Some(true));
+            let fresh = engine
+                .spawn_entity(SpawnEntitySpec {
+                    coreflame: Some(untouched),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room");
+
+            engine.state.destruction_entropy = 1.0;
+            engine.apply_golden_blood_corruption();
+            // The fresh entity reuses the freed ID but none of the despawned one's blood.
+            assert_eq!(fresh, corrupted);
+            let coreflame = |entity| engine.world.read(|ecs| ecs.coreflames.get(entity).copied());
+            assert_eq!(coreflame(fresh), Some(untouched));
+            assert_eq!(engine.flips_last_tick(), 0);
+        }
+    }
+
+    #[test]
+    fn same_master_seed_replays_culling_and_spawning_identically() {
+        // Culls one random entity and spawns one with random corruption every tick.
+        let run = |master_seed: u64| {
+            let config = EngineConfig {
+                master_seed,
+                ..EngineConfig::default()
+            };
+            let mut engine = AmphoreusEngine::with_world(
+                1 << 20,
+                config,
+                EcsWorld::from_ecs(corruption_world(200)),
+            )
+            .expect("config is valid");
+            for _ in 0..20 {
+                let live = engine.world.read(|ecs| ecs.sample_entities(usize::MAX, 0));
+                let victim = live[engine.rng().next_below(live.len() as u64) as usize];
+                let _ = engine.world.write(|ecs| ecs.despawn(victim));
+                let corruption_level = engine.rng().next_f64();
+                let _ = engine.spawn_entity(SpawnEntitySpec {
+                    coreflame: Some(Coreflame {
+                        power_level: 1.0,
+                        alignment: Path::Erudition,
+                    }),
+                    golden_blood: Some(GoldenBlood { corruption_level }),
+                    ..SpawnEntitySpec::default()
+                });
+                engine.tick();
+            }
+            let world = engine
+                .world
+                .read(|ecs| ecs.snapshot_entities(&ecs.sample_entities(usize::MAX, 0)));
+            (engine.state, world, engine.rng().next_u64())
+        };
+
+        let first = run(17);
+        assert_eq!(run(17), first);
+        let other = run(18);
+        assert_ne!(other.1, first.1);
+        assert_ne!(other.2, first.2);
+    }
+
+    #[test]
+    fn engine_generator_survives_the_page_and_restarts_on_a_new_seed() {
+        let config = EngineConfig {
+            master_seed: 5,
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(1 << 12, config, EcsWorld::new(0))
+            .expect("config is valid");
+        engine.rng().next_u64();
+
+        let path = temp_page("engine_rng");
+        engine.snapshot_to_eternal_page(&path);
+        let mut restored = AmphoreusEngine::restore_from_eternal_page(&path).expect("page reads");
+        let _ = fs::remove_file(&path);
+        assert_eq!(restored.rng().next_u64(), engine.rng().next_u64());
+
+        engine
+            .set_config(EngineConfig {
+                master_seed: 6,
+                ..config
+            })
+            .expect("config is valid");
+        assert_eq!(engine.rng().next_u64(), SimRng::new(6).next_u64());
+    }
+
+    /// An engine over a fresh world holding one corrupted entity with unit power.
+    fn corrupted_single(config: EngineConfig) -> (AmphoreusEngine, Entity) {
+        let mut engine = AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(4))
+            .expect("config is valid");
+        let entity = engine
+            .spawn_entity(SpawnEntitySpec {
+                coreflame: Some(Coreflame {
+                    power_level: 1.0,
+                    alignment: Path::Remembrance,
+                }),
+                golden_blood: Some(GoldenBlood {
+                    corruption_level: 0.9,
+                }),
+                ..SpawnEntitySpec::default()
+            })
+            .expect("arena has room");
+        (engine, entity)
+    }
+
+    fn overshooting(policy: ClampPolicy, corruption_order: CorruptionOrder) -> EngineConfig {
+        EngineConfig {
+            power_clamp_policy: policy,
+            power_decay_rate: 2.0,
+            corruption_order,
+            ..EngineConfig::default()
+        }
+    }
+
+    const BOTH_ORDERS: [CorruptionOrder; 2] = [
+        CorruptionOrder::Storage,
+        CorruptionOrder::Shuffled { seed: 3 },
+    ];
+
+    fn power_of(engine: &AmphoreusEngine, entity: Entity) -> Option<f64> {
+        engine
+            .world
+            .read(|ecs| ecs.coreflames.get(entity).map(|flame| flame.power_level))
+    }
+
+    #[test]
+    fn clamp_error_flags_decay_past_zero_instead_of_clamping() {
+        for order in BOTH_ORDERS {
+            let (mut engine, entity) = corrupted_single(overshooting(ClampPolicy::Error, order));
+            engine.apply_golden_blood_corruption();
+            assert_eq!(engine.clamped_last_tick(), [entity]);
+            assert_eq!(power_of(&engine, entity), Some(1.0));
+        }
+    }
+
+    #[test]
+    fn clamp_saturate_and_despawn_handle_decay_past_zero() {
+        for order in BOTH_ORDERS {
+            let (mut engine, entity) = corrupted_single(overshooting(ClampPolicy::Saturate, order));
+            engine.apply_golden_blood_corruption();
+            assert!(engine.clamped_last_tick().is_empty());
+            assert_eq!(power_of(&engine, entity), Some(0.0));
+
+            let (mut engine, entity) = corrupted_single(overshooting(ClampPolicy::Despawn, order));
+            engine.apply_golden_blood_corruption();
+            assert_eq!(engine.clamped_last_tick(), [entity]);
+            assert!(!engine.world.read(|ecs| ecs.is_alive(entity)));
+        }
+    }
+
+    #[test]
+    fn default_decay_rate_never_overshoots() {
+        let (mut engine, entity) = corrupted_single(EngineConfig {
+            power_clamp_policy: ClampPolicy::Error,
+            ..EngineConfig::default()
+        });
+        for _ in 0..50 {
+            engine.apply_golden_blood_corruption();
+            assert!(engine.clamped_last_tick().is_empty());
+        }
+        assert!(power_of(&engine, entity).is_some_and(|power| power > 0.0));
+    }
+
+    #[test]
+    fn exported_equation_round_trips_through_json() {
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            EngineConfig::default(),
+            EcsWorld::from_ecs(corruption_world(300)),
+        )
+        .expect("default config is valid");
+        engine.persistent_memories[PHAINON_MEMORY].log.trauma_index = 0.37;
+
+        let json = engine.export_equation();
+        let imported = AmphoreusEngine::import_equation(&json).expect("exported JSON parses");
+        assert_eq!(imported, engine.build_destruction_nodes());
+        assert_eq!(
+            evaluate_destruction_ast_with(&imported, &engine.config.eval),
+            evaluate_destruction_ast_with(&engine.build_destruction_nodes(), &engine.config.eval)
+        );
+
+        let designed = [
+            DestructionNode::EntityCount(12),
+            DestructionNode::ConflictEvent(0.1 + 0.2),
+            DestructionNode::EntropyMultiplier(1.0 / 3.0),
+            DestructionNode::ConflictEvent(0.5),
+        ];
+        let json = serde_json::to_string(&designed).expect("nodes serialize");
+        assert_eq!(
+            AmphoreusEngine::import_equation(&json).expect("JSON parses"),
+            designed
+        );
+        assert!(AmphoreusEngine::import_equation(r#"[{"EntityCount":-1}]"#).is_err());
+    }
+
+    #[test]
+    fn corruption_velocity_reports_the_signed_delta_of_each_tick() {
+        let config = EngineConfig {
+            black_tide_trigger: BlackTideTrigger::Threshold(2.0),
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            config,
+            EcsWorld::from_ecs(corruption_world(5_000)),
+        )
+        .expect("config is valid");
+        let world = engine.world.clone();
+        let average = || world.read(|ecs| ecs.average_corruption());
+        // The first tick measures against the engine's zero baseline.
+        engine.tick();
+
+        for _ in 0..4 {
+            let before = average();
+            assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+            let after = average();
+            assert!(after > before, "corruption pass should raise the average");
+            assert_eq!(engine.corruption_velocity(), after - before);
+        }
+
+        // Purging blood between ticks shows up as a negative velocity on the next one.
+        let before = average();
+        let _ = engine.world.write(|ecs| {
+            for blood in ecs.golden_blood.dense_pairs_mut().1 {
+                blood.corruption_level = 0.0;
+            }
+        });
+        engine.tick();
+        assert_eq!(engine.corruption_velocity(), average() - before);
+        assert!(engine.corruption_velocity() < 0.0);
+    }
+
+    #[test]
+    fn reseeding_keeps_or_clears_phainon_memory_as_configured() {
+        let scarred = MemoryLog {
+            trauma_index: 0.6,
+            retained_cycles: 12,
+        };
+        let reseeded_memory = |keep_phainon_memory| {
+            let mut engine = independent_engine(1 << 16);
+            engine.persistent_memories[PHAINON_MEMORY].log = scarred;
+            engine
+                .seed_world(WorldSeedConfig {
+                    citizens: 4,
+                    titans: 0,
+                    chrysos_heirs: 0,
+                    keep_phainon_memory,
+                    ..WorldSeedConfig::default()
+                })
+                .expect("seed fits");
+            let phainon = engine.flame_chase.phainon.expect("seeded Phainon");
+            let memory = engine
+                .world
+                .read(|ecs| ecs.memory_logs.get(phainon.index).copied());
+            (engine.persistent_memories[PHAINON_MEMORY].log, memory)
+        };
+
+        assert!(WorldSeedConfig::default().keep_phainon_memory);
+        assert_eq!(reseeded_memory(true), (scarred, Some(scarred)));
+        let fresh = MemoryLog::default();
+        assert_eq!(reseeded_memory(false), (fresh, Some(fresh)));
+    }
+
+    #[test]
+    fn flame_chase_handles_do_not_alias_citizens_after_a_reseed() {
+        let seed = WorldSeedConfig {
+            citizens: 4,
+            titans: 1,
+            chrysos_heirs: 1,
+            ..WorldSeedConfig::default()
+        };
+        let mut engine = independent_engine(1 << 16);
+        engine.seed_world(seed).expect("seed fits");
+        let before = engine.flame_chase;
+        engine.seed_world(seed).expect("seed fits");
+        let after = engine.flame_chase;
+
+        engine.world.read(|ecs| {
+            for (old, new) in [
+                (before.phainon, after.phainon),
+                (before.cyrene, after.cyrene),
+            ] {
+                let (old, new) = (old.expect("seeded"), new.expect("seeded"));
+                assert_eq!(old.index, new.index, "the reseed reuses the same slot");
+                assert_eq!(ecs.resolve(old), None);
+                assert!(ecs.is_handle_alive(new));
+            }
+        });
+    }
+
+    #[test]
+    fn eternal_page_restores_the_world_and_ticks_on_identically() {
+        let mut saved = independent_engine(1 << 20);
+        saved
+            .seed_world(WorldSeedConfig {
+                citizens: 200,
+                titans: 12,
+                chrysos_heirs: 16,
+                ..WorldSeedConfig::default()
+            })
+            .expect("seed fits");
+        let departed = saved
+            .spawn_entity(SpawnEntitySpec::default())
+            .expect("arena has room");
+        let _ = saved.world.write(|ecs| ecs.despawn(departed));
+        for _ in 0..4 {
+            saved.tick();
+        }
+
+        let path = temp_page("world_round_trip");
+        saved.snapshot_to_eternal_page(&path);
+        let trajectory = |engine: &mut AmphoreusEngine| {
+            (0..8)
+                .map(|_| {
+                    let result = engine.tick();
+                    let world = engine.world.read(|ecs| {
+                        (
+                            (ecs.entity_count(), ecs.spawned_total(), ecs.free_count()),
+                            ecs.average_corruption(),
+                            ecs.total_power(),
+                            (ecs.cohorts.len(), ecs.corruption_resistance.len()),
+                        )
+                    });
+                    (result, engine.state, world)
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected = trajectory(&mut saved);
+
+        let mut restored = AmphoreusEngine::restore_from_eternal_page(&path).expect("page reads");
+        let _ = fs::remove_file(&path);
+        let phainon = restored.flame_chase.phainon.expect("saved Phainon");
+        assert!(restored.world.read(|ecs| ecs.is_handle_alive(phainon)));
+        assert!(!restored.world.same_world(&saved.world));
+        assert!(!restored.world.same_world(&global_world()));
+        assert_eq!(trajectory(&mut restored), expected);
+    }
+
+    #[test]
+    fn pages_from_other_layout_versions_are_rejected() {
+        let path = temp_page("versioned");
+        independent_engine(1 << 12).snapshot_to_eternal_page(&path);
+        let current = fs::read(&path).expect("page was written");
+        let restore = |bytes: &[u8]| {
+            fs::write(&path, bytes).expect("temp dir is writable");
+            AmphoreusEngine::restore_from_eternal_page(&path)
+        };
+
+        let mut newer = current.clone();
+        newer[5..7].copy_from_slice(&(page::PAGE_VERSION + 1).to_le_bytes());
+        let newer = restore(&newer);
+
+        // Pages from before the version tag existed carry no version and count as version 1.
+        let mut unversioned = current[..5].to_vec();
+        unversioned[4] &= !0x40;
+        unversioned.extend_from_slice(&current[7..]);
+        let unversioned = restore(&unversioned);
+        let _ = fs::remove_file(&path);
+
+        assert!(matches!(
+            newer,
+            Err(RestoreError::UnsupportedVersion(version)) if version == page::PAGE_VERSION + 1
+        ));
+        assert!(matches!(
+            unversioned,
+            Err(RestoreError::UnsupportedVersion(1))
+        ));
+    }
+
+    #[test]
+    fn state_checkpoint_round_trips_progression_in_a_small_file() {
+        let mut engine = independent_engine(1 << 16);
+        engine.world_seed = WorldSeedConfig {
+            citizens: 50,
+            titans: 2,
+            chrysos_heirs: 2,
+            ..WorldSeedConfig::default()
+        };
+        engine.force_black_tide();
+        engine.force_black_tide();
+        engine.state.destruction_entropy = 0.42;
+
+        let state_path = temp_page("state_only");
+        let page_path = temp_page("state_only_full");
+        engine.save_state_only(&state_path);
+        engine.snapshot_to_eternal_page(&page_path);
+        let checkpoint = AmphoreusEngine::load_state_only(&state_path);
+        let wrong_kind = AmphoreusEngine::load_state_only(&page_path);
+        let sizes = [&state_path, &page_path].map(|path| fs::metadata(path).map(|m| m.len()));
+        let _ = fs::remove_file(&state_path);
+        let _ = fs::remove_file(&page_path);
+
+        let checkpoint = checkpoint.expect("checkpoint reads");
+        assert_eq!(checkpoint.state.cycle_count, 2);
+        assert_eq!(checkpoint.state.destruction_entropy, 0.42);
+        assert_eq!(checkpoint.black_tide_count, 2);
+        assert_eq!(checkpoint.config, engine.config);
+        assert!(matches!(wrong_kind, Err(RestoreError::NotAPage)));
+        let [state_size, page_size] = sizes.map(|size| size.expect("files were written"));
+        assert!(state_size * 10 < page_size, "{state_size} vs {page_size}");
+    }
+
+    #[test]
+    fn threats_ranked_pages_through_a_known_world_in_descending_order() {
+        let mut engine = independent_engine(1 << 16);
+        let powers = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0];
+        let entities: Vec<Entity> = powers
+            .iter()
+            .map(|&power_level| {
+                engine
+                    .spawn_entity(SpawnEntitySpec {
+                        coreflame: Some(Coreflame {
+                            power_level,
+                            alignment: Path::Remembrance,
+                        }),
+                        ..SpawnEntitySpec::default()
+                    })
+                    .expect("arena has room")
+            })
+            .collect();
+        engine
+            .spawn_entity(SpawnEntitySpec::default())
+            .expect("arena has room");
+
+        // Scores are the powers; equal scores rank by ascending ID.
+        let order = [5, 7, 4, 8, 2, 0, 9, 6, 1, 3];
+        let expected: Vec<(Entity, f64)> = order
+            .iter()
+            .map(|&idx| (entities[idx], powers[idx]))
+            .collect();
+        assert_eq!(engine.threats_ranked(0, usize::MAX), expected);
+        for limit in 1..=4 {
+            for offset in 0..=expected.len() {
+                let end = (offset + limit).min(expected.len());
+                assert_eq!(
+                    engine.threats_ranked(offset, limit),
+                    expected[offset..end],
+                    "offset {offset}, limit {limit}"
+                );
+            }
+        }
+        assert!(engine.threats_ranked(3, 0).is_empty());
+        assert!(engine.threats_ranked(usize::MAX, 5).is_empty());
+    }
+
+    /// `corruption_world` with one in five entities resisting corruption.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn resistant_corruption_world(n: u32) -> SoaEcs {
+        let mut ecs = corruption_world(n);
+        for entity in (0..n).step_by(5) {
+            ecs.corruption_resistance.insert(
+                entity,
+                CorruptionResistance {
+                    factor: f64::from(entity % 3) / 2.0,
+                },
+            );
+        }
+        ecs
+    }
+
+    /// The pass as it stood before the join: corrupt every blood into a slot-indexed lookup,
+    /// then decay every coreflame from that lookup.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn two_pass_corruption(ecs: &mut SoaEcs, local_entropy: f64, config: &EngineConfig) -> u32 {
+        let blood_gain = local_entropy * config.entropy_to_corruption;
+        let mut corruption_lookup = vec![0.0_f64; ecs.entity_span()];
+        let resistance = &ecs.corruption_resistance;
+        let (golden_entities, golden_data) = ecs.golden_blood.dense_pairs_mut();
+        let updates: Vec<(Entity, f64)> = golden_entities
+            .par_iter()
+            .copied()
+            .zip(golden_data.par_iter_mut())
+            .filter_map(|(entity, blood)| {
+                if blood.corruption_level < config.corruption_spread_threshold {
+                    return None;
+                }
+                let gain = blood_gain * resistance_factor(resistance, entity);
+                blood.corruption_level = (blood.corruption_level + gain).clamp(0.0, 1.0);
+                Some((entity, blood.corruption_level))
+            })
+            .collect();
+        for (entity, corruption_level) in updates {
+            corruption_lookup[entity as usize] = corruption_level;
+            let mut changed = WatchMask::GOLDEN_BLOOD;
+            if ecs.coreflames.get(entity).is_some() {
+                changed = changed | WatchMask::COREFLAME;
+            }
+            ecs.mark_changed(entity, changed);
+        }
+
+        let (coreflame_entities, coreflame_data) = ecs.coreflames.dense_pairs_mut();
+        coreflame_entities
+            .par_iter()
+            .copied()
+            .zip(coreflame_data.par_iter_mut())
+            .map(|(entity, coreflame)| {
+                let corruption_level = corruption_lookup[entity as usize];
+                if corruption_level <= 0.0 {
+                    return 0;
+                }
+                (coreflame.power_level, _) = config.power_clamp_policy.decay(
+                    coreflame.power_level,
+                    corruption_level,
+                    config.power_decay_rate,
+                );
+                let flipped = coreflame.alignment != Path::Destruction;
+                coreflame.alignment = Path::Destruction;
+                u32::from(flipped)
+            })
+            .sum()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn assert_same_components(a: &SoaEcs, b: &SoaEcs) {
+        assert_eq!(a.coreflames.dense_entities(), b.coreflames.dense_entities());
+        assert_eq!(a.coreflames.dense_data(), b.coreflames.dense_data());
+        assert_eq!(
+            a.golden_blood.dense_entities(),
+            b.golden_blood.dense_entities()
+        );
+        assert_eq!(a.golden_blood.dense_data(), b.golden_blood.dense_data());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn join_pass_matches_the_two_pass_lookup() {
+        let config = EngineConfig::default();
+        let mut joined = resistant_corruption_world(10_000);
+        let mut two_pass = resistant_corruption_world(10_000);
+        for local_entropy in [0.2, 0.7, 1.5] {
+            let (flips, _) = corrupt_golden_blood(&mut joined, local_entropy, &config);
+            assert_eq!(
+                flips,
+                two_pass_corruption(&mut two_pass, local_entropy, &config)
+            );
+            assert_same_components(&joined, &two_pass);
+        }
+    }
+
+    /// Times the join against the two-pass lookup; run with `--ignored --nocapture`.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    #[ignore = "benchmark over a 1M-entity world"]
+    fn bench_join_pass_against_two_pass_lookup() {
+        const ENTITIES: u32 = 1_000_000;
+        const PASSES: u32 = 10;
+        let config = EngineConfig::default();
+        let mut joined = resistant_corruption_world(ENTITIES);
+        let mut two_pass = resistant_corruption_world(ENTITIES);
+
+        let started = std::time::Instant::now();
+        for _ in 0..PASSES {
+            corrupt_golden_blood(&mut joined, 0.5, &config);
+        }
+        let join_time = started.elapsed() / PASSES;
+
+        let started = std::time::Instant::now();
+        for _ in 0..PASSES {
+            two_pass_corruption(&mut two_pass, 0.5, &config);
+        }
+        let two_pass_time = started.elapsed() / PASSES;
+
+        assert_same_components(&joined, &two_pass);
+        eprintln!("{ENTITIES} entities: join {join_time:?}/pass, two-pass {two_pass_time:?}/pass");
+    }
+
+    fn probabilistic_tide_ticks(master_seed: u64, entropy: f64, ticks: u64) -> Vec<u64> {
+        let config = EngineConfig {
+            master_seed,
+            black_tide_trigger: BlackTideTrigger::Probabilistic { curve: 1.0 },
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(1 << 16, config, EcsWorld::new(8))
+            .expect("config is valid");
+        engine.world_seed = WorldSeedConfig {
+            citizens: 0,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        // An empty world evaluates to almost no entropy, so the perturbation sets the level.
+        (0..ticks)
+            .filter(|_| {
+                engine.perturb_entropy(entropy);
+                engine.tick() == SimulationResult::BlackTideTriggered
+            })
+            .collect()
+    }
+
+    #[test]
+    fn probabilistic_tides_fire_on_seeded_cycles_and_more_often_at_high_entropy() {
+        let calm = probabilistic_tide_ticks(19, 0.2, 400);
+        assert!(!calm.is_empty());
+        assert_eq!(probabilistic_tide_ticks(19, 0.2, 400), calm);
+        assert_ne!(probabilistic_tide_ticks(20, 0.2, 400), calm);
+
+        let restless = probabilistic_tide_ticks(19, 0.8, 400);
+        assert_eq!(probabilistic_tide_ticks(19, 0.8, 400), restless);
+        assert!(
+            restless.len() > 2 * calm.len(),
+            "{} tides at 0.8 against {} at 0.2",
+            restless.len(),
+            calm.len()
+        );
+        assert!(probabilistic_tide_ticks(19, 0.0, 400).is_empty());
+    }
+
+    fn small_seed() -> WorldSeedConfig {
+        WorldSeedConfig {
+            citizens: 200,
+            titans: 12,
+            chrysos_heirs: 16,
+            ..WorldSeedConfig::default()
+        }
+    }
+
+    #[test]
+    fn engines_with_separate_worlds_evolve_independently() {
+        let mut first = independent_engine(1 << 20);
+        let reseeded = EngineConfig {
+            master_seed: 7,
+            ..EngineConfig::default()
+        };
+        let mut second = AmphoreusEngine::with_world(1 << 20, reseeded, EcsWorld::new(0))
+            .expect("config is valid");
+        first.seed_world(small_seed()).expect("seed fits");
+        second
+            .seed_world(WorldSeedConfig {
+                citizens: 320,
+                ..small_seed()
+            })
+            .expect("seed fits");
+        let count = |engine: &AmphoreusEngine| engine.world.read(|ecs| ecs.entity_count());
+        assert_ne!(count(&first), count(&second));
+
+        let first_before = first.state;
+        let second_before = (count(&second), second.state);
+        for _ in 0..5 {
+            first.tick();
+        }
+        assert_ne!(first.state, first_before);
+        assert_eq!((count(&second), second.state), second_before);
+
+        for _ in 0..5 {
+            second.tick();
+        }
+        assert_ne!(
+            first.state.destruction_entropy,
+            second.state.destruction_entropy
+        );
+    }
+
+    #[test]
+    fn restoring_a_page_leaves_other_worlds_alone() {
+        let mut saved = independent_engine(1 << 20);
+        saved.seed_world(small_seed()).expect("seed fits");
+        let bystander = independent_engine(1 << 20);
+        let _ = bystander.world.write(|ecs| {
+            ecs.spawn();
+        });
+
+        let path = temp_page("bystander");
+        saved.snapshot_to_eternal_page(&path);
+        let restored = AmphoreusEngine::restore_from_eternal_page(&path).expect("page reads");
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(bystander.world.read(|ecs| ecs.entity_count()), 1);
+        assert_eq!(
+            restored.world.read(|ecs| ecs.entity_count()),
+            saved.world.read(|ecs| ecs.entity_count())
+        );
+    }
+
+    #[test]
+    fn default_engines_share_the_process_wide_world() {
+        let first = AmphoreusEngine::new(1 << 16);
+        let mut second = AmphoreusEngine::new(1 << 16);
+        assert!(first.world.same_world(&second.world));
+        assert!(first.world.same_world(&global_world()));
+
+        let entity = second
+            .spawn_entity(SpawnEntitySpec::default())
+            .expect("arena has room");
+        assert_eq!(with_global_ecs(|ecs| ecs.is_alive(entity)), Some(true));
+        assert!(first.world.read(|ecs| ecs.is_alive(entity)));
+        assert_eq!(with_global_ecs_mut(|ecs| ecs.despawn(entity)), Some(true));
+    }
+
+    #[test]
+    fn same_rng_seed_builds_and_ticks_an_identical_world() {
+        let seeded = |rng_seed| {
+            let mut engine = independent_engine(1 << 20);
+            engine
+                .seed_world(WorldSeedConfig {
+                    rng_seed,
+                    ..small_seed()
+                })
+                .expect("seed fits");
+            engine
+        };
+        let world_bytes = |engine: &AmphoreusEngine| {
+            engine
+                .world
+                .read(|ecs| encode_to_vec(ecs, standard()))
+                .expect("world encodes")
+        };
+        let average = |engine: &AmphoreusEngine| engine.world.read(SoaEcs::average_corruption);
+
+        let mut first = seeded(11);
+        let mut twin = seeded(11);
+        let mut other = seeded(12);
+        assert_eq!(world_bytes(&first), world_bytes(&twin));
+        assert_eq!(average(&first), average(&twin));
+        assert_ne!(average(&first), average(&other));
+
+        for _ in 0..10 {
+            assert_eq!(first.tick(), twin.tick());
+            other.tick();
+        }
+        assert_eq!(first.state, twin.state);
+        assert_eq!(world_bytes(&first), world_bytes(&twin));
+        assert_ne!(world_bytes(&first), world_bytes(&other));
+        assert_ne!(
+            first.state.destruction_entropy,
+            other.state.destruction_entropy
+        );
+    }
+
+    fn uniformly_corrupted_engine(
+        entity_count: u32,
+        corruption_level: f64,
+        config: EngineConfig,
+    ) -> AmphoreusEngine {
+        let mut ecs = SoaEcs::with_capacity(entity_count as usize);
+        for _ in 0..entity_count {
+            let entity = ecs.spawn();
+            ecs.golden_blood
+                .insert(entity, GoldenBlood { corruption_level });
+        }
+        AmphoreusEngine::with_world(1 << 16, config, EcsWorld::from_ecs(ecs))
+            .expect("config is valid")
+    }
+
+    #[test]
+    fn entropy_sensitivities_match_the_analytic_partials() {
+        let mut engine = uniformly_corrupted_engine(1_000, 0.4, EngineConfig::default());
+        engine.persistent_memories[PHAINON_MEMORY].log = MemoryLog {
+            trauma_index: 0.5,
+            retained_cycles: 0,
+        };
+        let state = engine.state;
+        let sensitivity = engine.entropy_sensitivities();
+        assert_eq!(engine.state, state);
+        assert_eq!(engine.world.read(SoaEcs::entity_count), 1_000);
+
+        // entropy = (0.35 * n / 1e6 + 0.5 * c) * (1 + k * c) * (1 + 0.25 * t)
+        let (n, c, t, k) = (1_000.0, 0.4, 0.5, engine.config.corruption_to_entropy);
+        let additive = 0.35 * n / 1e6 + 0.5 * c;
+        let corruption_scale = 1.0 + k * c;
+        let memory_scale = 1.0 + 0.25 * t;
+        let expected = EntropySensitivity {
+            d_entity_count: 0.35e-6 * corruption_scale * memory_scale,
+            d_corruption: (0.5 * corruption_scale + k * additive) * memory_scale,
+            d_trauma: additive * corruption_scale * 0.25,
+        };
+        let close = |actual: f64, expected: f64| {
+            assert!(actual > 0.0, "{actual}");
+            assert!(
+                ((actual - expected) / expected).abs() < 1e-4,
+                "{actual} against {expected}"
+            );
+        };
+        close(sensitivity.d_entity_count, expected.d_entity_count);
+        close(sensitivity.d_corruption, expected.d_corruption);
+        close(sensitivity.d_trauma, expected.d_trauma);
+        // Corruption dominates a small world; one more entity barely registers.
+        assert!(sensitivity.d_corruption > sensitivity.d_trauma);
+        assert!(sensitivity.d_trauma > sensitivity.d_entity_count);
+
+        // Once entropy saturates at 1.0, no small nudge moves it.
+        let saturated = uniformly_corrupted_engine(
+            1_000,
+            0.9,
+            EngineConfig {
+                corruption_to_entropy: 3.0,
+                ..EngineConfig::default()
+            },
+        );
+        assert_eq!(
+            saturated.entropy_sensitivities(),
+            EntropySensitivity::default()
+        );
+    }
+
+    #[test]
+    fn collapse_momentum_rises_with_corruption_velocity_and_entropy_rate() {
+        let config = EngineConfig {
+            black_tide_trigger: BlackTideTrigger::Threshold(2.0),
+            ..EngineConfig::default()
+        };
+        let mut engine = uniformly_corrupted_engine(1_000, 0.1, config);
+        engine.tick();
+        engine.tick();
+        // Only the slow entropy drift of an unchanging world registers.
+        let calm = engine.collapse_momentum();
+        assert!(calm < 0.01, "{calm}");
+
+        let (mut velocity, mut entropy_rate, mut momentum) = (0.0, 0.0, calm);
+        for step in [0.002, 0.004, 0.008, 0.016] {
+            let _ = engine.world.write(|ecs| {
+                for blood in ecs.golden_blood.dense_pairs_mut().1 {
+                    blood.corruption_level += step;
+                }
+            });
+            engine.tick();
+            assert!(engine.corruption_velocity() > velocity);
+            assert!(engine.entropy_rate() > entropy_rate);
+            assert!(engine.collapse_momentum() > momentum);
+            assert!(engine.collapse_momentum() < 1.0);
+            velocity = engine.corruption_velocity();
+            entropy_rate = engine.entropy_rate();
+            momentum = engine.collapse_momentum();
+        }
+        assert_eq!(engine.population_rate(), 0.0);
+
+        // A falling world carries no momentum, and neither does one with every weight off.
+        let _ = engine.world.write(|ecs| {
+            for blood in ecs.golden_blood.dense_pairs_mut().1 {
+                blood.corruption_level = 0.0;
+            }
+        });
+        engine.tick();
+        assert_eq!(engine.collapse_momentum(), 0.0);
+        engine.entropy_rate = 0.5;
+        engine.config.momentum_weights = MomentumWeights {
+            entropy_rate: 0.0,
+            corruption_velocity: 0.0,
+            population_rate: 0.0,
+        };
+        assert_eq!(engine.collapse_momentum(), 0.0);
+    }
+
+    #[test]
+    fn harsher_collapses_reseed_fewer_citizens_down_to_min_fraction() {
+        let citizens_after_collapse_at = |reseed_policy: ReseedPolicy, entropy: f64| {
+            let config = EngineConfig {
+                black_tide_trigger: BlackTideTrigger::Threshold(0.05),
+                reseed_policy,
+                ..EngineConfig::default()
+            };
+            let mut engine = AmphoreusEngine::with_world(1 << 22, config, EcsWorld::new(0))
+                .expect("config is valid");
+            engine.world_seed = WorldSeedConfig {
+                citizens: 1_000,
+                titans: 0,
+                chrysos_heirs: 0,
+                ..WorldSeedConfig::default()
+            };
+            // An empty world evaluates to zero entropy, so the collapse happens at `entropy`.
+            engine.perturb_entropy(entropy);
+            assert_eq!(engine.tick(), SimulationResult::BlackTideTriggered);
+            assert_eq!(engine.state.destruction_entropy, entropy);
+            // Everyone but the flame-chase pair is a citizen.
+            engine.world.read(SoaEcs::entity_count) - 2
+        };
+
+        let scaled = ReseedPolicy::ScaleBySeverity { min_fraction: 0.25 };
+        let reseeded: Vec<usize> = [0.2, 0.5, 0.7, 0.9, 1.0]
+            .into_iter()
+            .map(|entropy| citizens_after_collapse_at(scaled, entropy))
+            .collect();
+        assert_eq!(reseeded, [800, 500, 300, 250, 250]);
+        assert_eq!(citizens_after_collapse_at(ReseedPolicy::Exact, 0.9), 1_000);
+    }
+
+    #[test]
+    fn arena_audit_passes_after_seeding_and_flags_induced_drift() {
+        let mut engine = independent_engine(1 << 22);
+        assert_eq!(engine.audit_arena_consistency(), Ok(()));
+        engine.seed_world(small_seed()).expect("seed fits");
+        assert_eq!(engine.audit_arena_consistency(), Ok(()));
+        for _ in 0..5 {
+            engine.tick();
+        }
+        assert_eq!(engine.audit_arena_consistency(), Ok(()));
+
+        // Entities spawned behind the arena's back leave it short.
+        let _ = engine.world.write(|ecs| {
+            for _ in 0..10 {
+                let entity = ecs.spawn();
+                ecs.golden_blood.insert(entity, GoldenBlood::default());
+            }
+        });
+        let Err(ConsistencyError::ArenaDrift {
+            arena_offset,
+            expected_min,
+            ..
+        }) = engine.audit_arena_consistency()
+        else {
+            panic!("unaccounted spawns should be flagged");
+        };
+        assert!(arena_offset < expected_min);
+
+        // And arena bytes nobody spawned into leave it long.
+        let mut engine = independent_engine(1 << 22);
+        engine.seed_world(small_seed()).expect("seed fits");
+        let _ = engine.arena.alloc_bytes(4_096, ENTITY_ALIGN);
+        let Err(ConsistencyError::ArenaDrift {
+            arena_offset,
+            expected_max,
+            ..
+        }) = engine.audit_arena_consistency()
+        else {
+            panic!("leaked arena bytes should be flagged");
+        };
+        assert!(arena_offset > expected_max);
+    }
+
+    #[test]
+    fn preserved_chrysos_heirs_survive_a_black_tide_with_their_handles() {
+        let config = EngineConfig {
+            preserve_chrysos_heirs: true,
+            ..EngineConfig::default()
+        };
+        let mut engine = AmphoreusEngine::with_world(1 << 22, config, EcsWorld::new(0))
+            .expect("config is valid");
+        engine.seed_world(small_seed()).expect("seed fits");
+        let world = engine.world.clone();
+        let heirs = || {
+            world.read(|ecs| {
+                ecs.cohorts
+                    .iter()
+                    .filter(|&(_, &cohort)| cohort == Cohort::ChrysosHeir)
+                    .map(|(entity, _)| ecs.handle(entity).expect("heirs are live"))
+                    .collect::<Vec<_>>()
+            })
+        };
+        let before = heirs();
+        assert_eq!(before.len(), 16);
+
+        engine.perturb_entropy(1.0);
+        assert_eq!(engine.tick(), SimulationResult::BlackTideTriggered);
+        // The reseed only tops heirs back up, so the survivors are all of them.
+        assert_eq!(heirs(), before);
+        assert_eq!(engine.audit_arena_consistency(), Ok(()));
+    }
+
+    #[test]
+    fn registered_memories_respawn_with_their_logs_after_a_black_tide() {
+        let mut engine = independent_engine(1 << 22);
+        engine.seed_world(small_seed()).expect("seed fits");
+        let sidekick_flame = Coreflame {
+            power_level: 0.8,
+            alignment: Path::Remembrance,
+        };
+        let sidekick = engine
+            .spawn_entity(SpawnEntitySpec {
+                coreflame: Some(sidekick_flame),
+                memory_log: Some(MemoryLog {
+                    retained_cycles: 4,
+                    trauma_index: 0.3,
+                }),
+                ..SpawnEntitySpec::default()
+            })
+            .expect("arena has room");
+        assert!(engine.register_persistent_memory(sidekick));
+        assert!(!engine.register_persistent_memory(sidekick));
+        assert_eq!(engine.persistent_memories().len(), 2);
+        let old_handle = engine.persistent_memories()[1]
+            .handle
+            .expect("registered live");
+
+        engine.tick();
+        let advanced = engine.persistent_memories()[1].log;
+        assert_eq!(advanced.retained_cycles, 5);
+        engine.perturb_entropy(1.0);
+        assert_eq!(engine.tick(), SimulationResult::BlackTideTriggered);
+
+        let memory = engine.persistent_memories()[1];
+        let new_handle = memory.handle.expect("respawned after the reseed");
+        assert_ne!(new_handle, old_handle);
+        engine.world.read(|ecs| {
+            assert_eq!(ecs.resolve(old_handle), None);
+            let entity = ecs
+                .resolve(new_handle)
+                .expect("handle points at the respawn");
+            assert_eq!(ecs.memory_logs.get(entity), Some(&memory.log));
+            assert_eq!(ecs.coreflames.get(entity), Some(&sidekick_flame));
+        });
+        assert!(memory.log.retained_cycles > advanced.retained_cycles);
+    }
+
+    #[test]
+    fn seeding_a_tiny_arena_reports_exhaustion_with_partial_counts() {
+        let mut engine = independent_engine(4096);
+        let err = engine.seed_world(small_seed()).unwrap_err();
+        assert_eq!(err.cause, SpawnError::ArenaExhausted);
+        assert_eq!(err.report.requested, 200 + 12 + 16 + 2);
+        assert!(err.report.spawned > 0 && err.report.spawned < err.report.requested);
+        assert!(!err.report.cancelled);
+        assert_eq!(
+            engine.world.read(|ecs| ecs.entity_count()),
+            err.report.spawned as usize
+        );
+    }
+
+    #[test]
+    fn seeding_with_room_spawns_everything_requested() {
+        let mut engine = independent_engine(1 << 22);
+        let report = engine.seed_world(small_seed()).expect("small seed fits");
+        assert_eq!(report.spawned, report.requested);
+        assert_eq!(
+            engine.world.read(|ecs| ecs.entity_count()),
+            report.spawned as usize
+        );
+    }
+
+    #[test]
+    fn factions_spawn_serialize_and_leave_with_their_entities() {
+        let mut engine = independent_engine(1 << 16);
+        let spawned: Vec<Entity> = (0..30_u16)
+            .map(|i| {
+                engine
+                    .spawn_entity(SpawnEntitySpec {
+                        golden_blood: Some(GoldenBlood::default()),
+                        faction: Some(Faction(i % 3)),
+                        ..SpawnEntitySpec::default()
+                    })
+                    .expect("arena has room")
+            })
+            .collect();
+        let members = |world: &SoaEcs, faction: u16| {
+            world
+                .factions
+                .iter()
+                .filter(|&(_, &tag)| tag == Faction(faction))
+                .count()
+        };
+        engine.world.read(|ecs| {
+            assert_eq!([0, 1, 2].map(|faction| members(ecs, faction)), [10; 3]);
+            assert_eq!(ecs.factions.get(spawned[4]), Some(&Faction(1)));
+        });
+
+        let bytes = engine
+            .world
+            .read(|ecs| encode_to_vec(ecs, standard()))
+            .expect("world encodes");
+        let (decoded, _): (SoaEcs, usize) =
+            bincode::serde::decode_from_slice(&bytes, standard()).expect("world decodes");
+        assert_eq!([0, 1, 2].map(|faction| members(&decoded, faction)), [10; 3]);
+        assert_eq!(decoded.factions.get(spawned[29]), Some(&Faction(2)));
+
+        let _ = engine.world.write(|ecs| {
+            ecs.despawn(spawned[0]);
+            assert_eq!(ecs.factions.get(spawned[0]), None);
+            assert_eq!(members(ecs, 0), 9);
+            ecs.clear_for_black_tide();
+            assert!(ecs.factions.is_empty());
+        });
+    }
+
+    #[test]
+    fn short_lived_entities_despawn_after_their_lifespan() {
+        let mut engine = independent_engine(1 << 16);
+        let mut spawn_with = |lifespan: Option<u64>| {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    coreflame: Some(Coreflame::default()),
+                    lifespan: lifespan.map(|remaining_cycles| Lifespan { remaining_cycles }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room")
+        };
+        let mortals = [
+            (spawn_with(Some(1)), 1),
+            (spawn_with(Some(3)), 3),
+            (spawn_with(Some(5)), 5),
+        ];
+        let immortal = spawn_with(None);
+
+        for tick in 1..=6 {
+            assert_eq!(engine.tick(), SimulationResult::TickAdvanced);
+            for (entity, lifespan) in mortals {
+                assert_eq!(
+                    engine.world.read(|ecs| ecs.is_alive(entity)),
+                    tick < lifespan,
+                    "lifespan {lifespan} after {tick} ticks"
+                );
+            }
+            assert!(engine.pending_despawns.is_empty());
+        }
+        assert!(engine.world.read(|ecs| ecs.is_alive(immortal)));
+        assert!(engine.world.read(|ecs| ecs.lifespans.is_empty()));
+    }
+
+    #[test]
+    fn lifespans_do_not_leak_across_a_black_tide() {
+        let mut engine = independent_engine(1 << 20);
+        engine.seed_world(small_seed()).expect("seed fits");
+        for remaining_cycles in 1..=3 {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    lifespan: Some(Lifespan { remaining_cycles }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room");
+        }
+        engine.force_black_tide();
+
+        let reseeded = engine.world.read(|ecs| ecs.entity_count());
+        assert!(engine.world.read(|ecs| ecs.lifespans.is_empty()));
+        for _ in 0..3 {
+            engine.tick();
+            assert_eq!(engine.world.read(|ecs| ecs.entity_count()), reseeded);
+        }
     }
 }
@@ -1,39 +1,253 @@
-#[cfg(not(target_arch = "wasm32"))]
-use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// AST for computing global Destruction entropy.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Leaves evaluate to their weighted contribution, or to a factor for `EntropyMultiplier`;
+/// `Sum` and `Product` combine their children, so branches can be weighted independently.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DestructionNode {
     EntityCount(u32),
+    /// Entity count on a log scale that keeps resolving differences up to `u32::MAX`.
+    LogEntityCount(u32),
     ConflictEvent(f64),
     EntropyMultiplier(f64),
+    /// Sum of the children's values; an empty sum is 0.
+    Sum(Vec<DestructionNode>),
+    /// Product of the children's values; an empty product is 1.
+    Product(Vec<DestructionNode>),
+}
+
+/// Post-processing applied to an evaluated entropy score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvalConfig {
+    /// Round the score to this many decimal places, hiding last-bit floating differences
+    /// between the parallel and serial paths.
+    pub round_decimals: Option<u32>,
+}
+
+/// `evaluate_destruction_ast` followed by the rounding requested in `config`.
+pub fn evaluate_destruction_ast_with(nodes: &[DestructionNode], config: &EvalConfig) -> f64 {
+    let entropy = evaluate_destruction_ast(nodes);
+    match config.round_decimals {
+        Some(decimals) => {
+            let scale = 10_f64.powi(decimals.min(15) as i32);
+            ((entropy * scale).round() / scale).clamp(0.0, 1.0)
+        }
+        None => entropy,
+    }
 }
 
 /// Deterministically evaluates an entropy score in `[0.0, 1.0]`.
+///
+/// The top-level slice is the flat form: `(sum of non-multiplier nodes) * (product of
+/// multipliers)`, clamped. `Sum` and `Product` nodes count as additive terms there. Every fold,
+/// at every depth, runs over its terms sorted by value, so any permutation of siblings gives a
+/// bit-identical result.
 pub fn evaluate_destruction_ast(nodes: &[DestructionNode]) -> f64 {
-    let mut base_entropy = 0.0_f64;
-    let mut multiplier = 1.0_f64;
+    let mut additive = Vec::with_capacity(nodes.len());
+    let mut multipliers = Vec::new();
+
+    for node in nodes {
+        match node {
+            DestructionNode::EntropyMultiplier(_) => multipliers.push(node_value(node)),
+            _ => additive.push(node_value(node)),
+        }
+    }
+
+    let entropy = sorted_sum(additive) * sorted_product(multipliers);
+    // Near-empty worlds must never leak NaN into the black-tide comparison.
+    if entropy.is_nan() {
+        return 0.0;
+    }
+    entropy.clamp(0.0, 1.0)
+}
+
+/// Unclamped value of one node and its subtree.
+fn node_value(node: &DestructionNode) -> f64 {
+    match node {
+        DestructionNode::EntityCount(count) => {
+            // Scales toward 0.35 at one million entities.
+            let normalized = (*count as f64 / 1_000_000.0).clamp(0.0, 1.0);
+            normalized * 0.35
+        }
+        DestructionNode::LogEntityCount(count) => {
+            // `ln(1 + count) / ln(1 + u32::MAX)`, so zero contributes nothing and the
+            // largest count the same 0.35 as a saturated linear count.
+            let normalized = (*count as f64).ln_1p() / (u32::MAX as f64).ln_1p();
+            normalized * 0.35
+        }
+        DestructionNode::ConflictEvent(severity) => severity.clamp(0.0, 1.0) * 0.5,
+        DestructionNode::EntropyMultiplier(scale) => scale.clamp(0.0, 4.0),
+        DestructionNode::Sum(children) => sorted_sum(children.iter().map(node_value).collect()),
+        DestructionNode::Product(children) => {
+            sorted_product(children.iter().map(node_value).collect())
+        }
+    }
+}
+
+fn sorted_sum(mut terms: Vec<f64>) -> f64 {
+    terms.sort_unstable_by(f64::total_cmp);
+    terms.iter().sum()
+}
+
+fn sorted_product(mut factors: Vec<f64>) -> f64 {
+    factors.sort_unstable_by(f64::total_cmp);
+    factors.iter().product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SimRng;
+
+    fn conflict_nodes() -> Vec<DestructionNode> {
+        let mut nodes = vec![DestructionNode::EntityCount(437_113)];
+        nodes.extend((0..2_000).map(|idx| DestructionNode::ConflictEvent(f64::from(idx) * 1e-7)));
+        nodes.push(DestructionNode::EntropyMultiplier(1.000_3));
+        nodes
+    }
 
     #[cfg(not(target_arch = "wasm32"))]
-    let ordered_nodes: Vec<DestructionNode> = nodes.par_iter().copied().collect();
-    #[cfg(target_arch = "wasm32")]
-    let ordered_nodes: Vec<DestructionNode> = nodes.to_vec();
+    #[test]
+    fn rounded_entropy_matches_between_parallel_and_serial_evaluation() {
+        let config = EvalConfig {
+            round_decimals: Some(6),
+        };
+        let nodes = conflict_nodes();
+        let evaluate_on = |threads: usize, nodes: &[DestructionNode]| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .expect("pool builds")
+                .install(|| evaluate_destruction_ast_with(nodes, &config))
+        };
 
-    for node in ordered_nodes {
-        match node {
-            DestructionNode::EntityCount(count) => {
-                // Scales toward 0.35 at one million entities.
-                let normalized = (count as f64 / 1_000_000.0).clamp(0.0, 1.0);
-                base_entropy += normalized * 0.35;
+        let parallel = evaluate_on(8, &nodes);
+        let serial = evaluate_on(1, &nodes);
+        assert_eq!(parallel.to_bits(), serial.to_bits());
+
+        // The fold sorts its terms, so reversing the input changes nothing either.
+        let reversed: Vec<_> = nodes.iter().rev().cloned().collect();
+        assert_eq!(evaluate_on(1, &reversed).to_bits(), serial.to_bits());
+        assert_eq!(serial, (serial * 1e6).round() / 1e6);
+    }
+
+    #[test]
+    fn round_decimals_rounds_and_none_leaves_the_score_alone() {
+        let nodes = [DestructionNode::ConflictEvent(0.246_913_578)];
+        let raw = evaluate_destruction_ast(&nodes);
+        assert_eq!(
+            evaluate_destruction_ast_with(&nodes, &EvalConfig::default()),
+            raw
+        );
+        let rounded = evaluate_destruction_ast_with(
+            &nodes,
+            &EvalConfig {
+                round_decimals: Some(4),
+            },
+        );
+        assert_eq!(rounded, 0.1235);
+    }
+
+    #[test]
+    fn log_entity_count_keeps_resolving_where_the_linear_count_saturates() {
+        let log = |count| evaluate_destruction_ast(&[DestructionNode::LogEntityCount(count)]);
+        let linear = |count| evaluate_destruction_ast(&[DestructionNode::EntityCount(count)]);
+        let full = (u32::MAX as f64).ln_1p();
+
+        assert_eq!(log(0), 0.0);
+        for count in [9, 999, 1_000_000, 1_000_000_000] {
+            let expected = (count as f64).ln_1p() / full * 0.35;
+            assert!((log(count) - expected).abs() < 1e-12, "{count}");
+        }
+        assert!((log(u32::MAX) - 0.35).abs() < 1e-12);
+
+        // Each thousandfold growth adds about the same amount on the log scale.
+        let step = log(999_999) - log(999);
+        assert!((log(999_999_999) - log(999_999) - step).abs() < 1e-6);
+        assert_eq!(linear(1_000_000), linear(1_000_000_000));
+        assert!(log(1_000_000_000) > log(1_000_000));
+    }
+
+    fn random_node(rng: &mut SimRng, depth: u32) -> DestructionNode {
+        let kinds = if depth == 0 { 4 } else { 6 };
+        match rng.next_below(kinds) {
+            0 => DestructionNode::EntityCount(rng.next_below(2_000_000) as u32),
+            1 => DestructionNode::LogEntityCount(rng.next_u64() as u32),
+            2 => DestructionNode::ConflictEvent(rng.next_f64() * 1e-3),
+            3 => DestructionNode::EntropyMultiplier(rng.next_f64() * 4.0),
+            kind => {
+                let children = (0..rng.next_below(5))
+                    .map(|_| random_node(rng, depth - 1))
+                    .collect();
+                if kind == 4 {
+                    DestructionNode::Sum(children)
+                } else {
+                    DestructionNode::Product(children)
+                }
             }
-            DestructionNode::ConflictEvent(severity) => {
-                base_entropy += severity.clamp(0.0, 1.0) * 0.5;
+        }
+    }
+
+    fn shuffle(nodes: &mut [DestructionNode], rng: &mut SimRng) {
+        for i in (1..nodes.len()).rev() {
+            nodes.swap(i, rng.next_below(i as u64 + 1) as usize);
+        }
+        for node in nodes {
+            if let DestructionNode::Sum(children) | DestructionNode::Product(children) = node {
+                shuffle(children, rng);
             }
-            DestructionNode::EntropyMultiplier(scale) => {
-                multiplier *= scale.clamp(0.0, 4.0);
+        }
+    }
+
+    #[test]
+    fn shuffled_children_evaluate_bit_identically() {
+        for seed in 0..500 {
+            let mut rng = SimRng::new(seed);
+            let nodes: Vec<DestructionNode> = (0..rng.next_below(12))
+                .map(|_| random_node(&mut rng, 3))
+                .collect();
+            let expected = evaluate_destruction_ast(&nodes);
+
+            let mut shuffled = nodes.clone();
+            let mut shuffle_rng = SimRng::new(seed ^ 0x5eed);
+            for _ in 0..4 {
+                shuffle(&mut shuffled, &mut shuffle_rng);
+                assert_eq!(
+                    evaluate_destruction_ast(&shuffled).to_bits(),
+                    expected.to_bits(),
+                    "seed {seed}"
+                );
             }
         }
     }
 
-    (base_entropy * multiplier).clamp(0.0, 1.0)
+    #[test]
+    fn three_node_tree_matches_the_flat_form() {
+        for (count, conflict, multiplier) in [
+            (0, 0.0, 1.0),
+            (12_345, 0.4, 1.3),
+            (900_000, 0.95, 3.2),
+            (5_000_000, 1.0, 4.0),
+        ] {
+            let flat = [
+                DestructionNode::EntityCount(count),
+                DestructionNode::ConflictEvent(conflict),
+                DestructionNode::EntropyMultiplier(multiplier),
+            ];
+            let tree = [DestructionNode::Product(vec![
+                DestructionNode::Sum(vec![
+                    DestructionNode::EntityCount(count),
+                    DestructionNode::ConflictEvent(conflict),
+                ]),
+                DestructionNode::EntropyMultiplier(multiplier),
+            ])];
+            let legacy = ((f64::from(count) / 1_000_000.0).clamp(0.0, 1.0) * 0.35 + conflict * 0.5)
+                * multiplier;
+
+            let expected = legacy.clamp(0.0, 1.0).to_bits();
+            assert_eq!(evaluate_destruction_ast(&flat).to_bits(), expected);
+            assert_eq!(evaluate_destruction_ast(&tree).to_bits(), expected);
+        }
+    }
 }
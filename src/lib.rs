@@ -1,5 +1,9 @@
+#![allow(non_snake_case)]
+
 pub mod arena;
 pub mod ecs;
 pub mod engine;
 pub mod equation;
 pub mod observer;
+pub mod page;
+pub mod rng;
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use std::thread;
 use std::time::Duration;
 
@@ -9,13 +11,16 @@ fn main() {
     init_global_ecs(1_500_000);
 
     let mut engine = AmphoreusEngine::new(256 * 1024 * 1024);
-    engine.seed_world(WorldSeedConfig {
+    if let Err(err) = engine.seed_world(WorldSeedConfig {
         citizens: 20_000,
         titans: 500,
         chrysos_heirs: 128,
-    });
+        ..WorldSeedConfig::default()
+    }) {
+        eprintln!("world seeding fell short: {err}");
+    }
 
-    let runtime = ObserverRuntime::spawn(engine, 60, 360);
+    let (runtime, _commands) = ObserverRuntime::spawn(engine, 60, 360);
     let shared = runtime.shared_snapshot();
 
     for _ in 0..6 {
@@ -26,7 +31,7 @@ fn main() {
             snapshot.state.cycle_count,
             snapshot.state.destruction_entropy,
             snapshot.state.time_concept_active,
-            snapshot.entropy_samples.len()
+            snapshot.samples.len()
         );
     }
 }
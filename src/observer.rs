@@ -1,25 +1,188 @@
 use std::collections::VecDeque;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::thread::{self, JoinHandle};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, OnceLock};
+use std::thread::{self, JoinHandle, ScopedJoinHandle, Thread};
 use std::time::{Duration, Instant};
 
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 
-use crate::engine::{AmphoreusEngine, GlobalState};
+use crate::arena::ArenaStats;
+use crate::ecs::Path;
+use crate::engine::{
+    AmphoreusEngine, BlackTideTrigger, FlameChaseSnapshot, GlobalState, SimulationResult,
+    SpawnEntitySpec, WorldSeedConfig,
+};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Notable engine events attached to the entropy sample recorded on the same tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntropyEvent {
+    BlackTide,
+}
+
+/// One entropy reading and the tick it was taken on.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntropySample {
+    pub entropy: f64,
+    /// The event that fired on that tick, if any.
+    pub event: Option<EntropyEvent>,
+    /// Seconds the reading held before the next one replaced it.
+    ///
+    /// The newest sample is still holding, so its duration stays 0.0 until the next tick.
+    pub held_secs: f64,
+}
+
+/// Aggregates over the entropy samples of one snapshot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntropyStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// Population standard deviation.
+    pub stddev: f64,
+    /// The newest sample.
+    pub last: f64,
+}
+
+impl EntropyStats {
+    /// Stats over `samples`, or `None` when there are none.
+    pub fn from_samples(samples: &[EntropySample]) -> Option<Self> {
+        let last = samples.last()?.entropy;
+        let count = samples.len() as f64;
+        let (min, max, sum) = samples.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY, 0.0),
+            |(min, max, sum), sample| {
+                let entropy = sample.entropy;
+                (min.min(entropy), max.max(entropy), sum + entropy)
+            },
+        );
+        let mean = sum / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.entropy - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        Some(Self {
+            min,
+            max,
+            mean,
+            stddev: variance.sqrt(),
+            last,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ObserverSnapshot {
     pub state: GlobalState,
-    pub entropy_samples: Vec<f64>,
+    pub samples: Vec<EntropySample>,
+    pub total_power: f64,
+    pub flame_chase: FlameChaseSnapshot,
+    pub flips_last_tick: u32,
+    pub dominant_path: Path,
+    /// Change in average corruption over the last tick; see `AmphoreusEngine::corruption_velocity`.
+    pub corruption_velocity: f64,
+    /// See `AmphoreusEngine::collapse_momentum`.
+    pub collapse_momentum: f64,
+    /// See `AmphoreusArena::stats`.
+    pub arena: ArenaStats,
+    /// See `AmphoreusEngine::failed_spawns`; counts `EngineCommand::Spawn` commands the engine
+    /// could not carry out.
+    pub failed_spawns: u64,
+    /// Stats over `samples`, computed with them on the engine thread; `None` when empty.
+    pub entropy_stats: Option<EntropyStats>,
+}
+
+impl ObserverSnapshot {
+    /// Captures the engine's current observable state alongside the given samples.
+    fn capture(engine: &AmphoreusEngine, samples: Vec<EntropySample>) -> Self {
+        Self {
+            state: engine.state,
+            entropy_stats: EntropyStats::from_samples(&samples),
+            samples,
+            total_power: engine.world().read(|ecs| ecs.total_power()),
+            flame_chase: engine.flame_chase_snapshot(),
+            flips_last_tick: engine.flips_last_tick(),
+            dominant_path: engine.world().read(|ecs| ecs.dominant_path()),
+            corruption_velocity: engine.corruption_velocity(),
+            collapse_momentum: engine.collapse_momentum(),
+            arena: engine.arena.stats(),
+            failed_spawns: engine.failed_spawns(),
+        }
+    }
+
+    /// Drops all but the newest `max_points` samples, leaving the rest of the snapshot as is.
+    ///
+    /// `entropy_stats` keeps describing the full history the engine thread published.
+    pub fn retain_latest_samples(&mut self, max_points: usize) {
+        if self.samples.len() > max_points {
+            let excess = self.samples.len() - max_points;
+            self.samples.drain(..excess);
+        }
+    }
+
+    /// Plain entropy values with event annotations stripped.
+    pub fn entropy_samples(&self) -> Vec<f64> {
+        self.samples.iter().map(|sample| sample.entropy).collect()
+    }
+
+    /// Mean entropy weighted by how long each sample held, so uneven tick spacing from
+    /// catch-up bursts does not skew it.
+    ///
+    /// Falls back to the plain mean while no sample has a recorded duration yet, and returns
+    /// `None` for an empty buffer.
+    pub fn time_weighted_mean_entropy(&self) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let (weighted, total_secs) =
+            self.samples
+                .iter()
+                .fold((0.0, 0.0), |(weighted, total), sample| {
+                    (
+                        weighted + sample.entropy * sample.held_secs,
+                        total + sample.held_secs,
+                    )
+                });
+        if total_secs > 0.0 {
+            return Some(weighted / total_secs);
+        }
+        let sum: f64 = self.samples.iter().map(|sample| sample.entropy).sum();
+        Some(sum / self.samples.len() as f64)
+    }
+
+    /// Bins the sampled entropy into `buckets` equal-width bins spanning `[0.0, 1.0]`.
+    ///
+    /// A value of exactly 1.0 lands in the last bin; an empty buffer yields all zeros.
+    pub fn entropy_histogram(&self, buckets: usize) -> Vec<u32> {
+        let mut histogram = vec![0_u32; buckets];
+        if buckets == 0 {
+            return histogram;
+        }
+
+        for sample in &self.samples {
+            let bin = (sample.entropy.clamp(0.0, 1.0) * buckets as f64) as usize;
+            histogram[bin.min(buckets - 1)] += 1;
+        }
+        histogram
+    }
 }
 
+/// Cheaply cloneable handle to the latest snapshot; clones share one buffer.
+///
+/// The handle is `Send + Sync`, so any number of reader threads may poll it concurrently.
 #[derive(Clone)]
 pub struct SharedObserverSnapshot {
     inner: Arc<RwLock<ObserverSnapshot>>,
 }
 
+const _: () = {
+    const fn assert_shareable<T: Clone + Send + Sync>() {}
+    assert_shareable::<SharedObserverSnapshot>();
+};
+
 impl SharedObserverSnapshot {
     pub fn new(initial: ObserverSnapshot) -> Self {
         Self {
@@ -31,95 +194,772 @@ impl SharedObserverSnapshot {
         self.inner.read().clone()
     }
 
+    /// Copies the latest snapshot into `buf`, reusing its sample buffer.
+    ///
+    /// Once `buf` has held a full sample window, repeated reads do not allocate.
+    pub fn read_into(&self, buf: &mut ObserverSnapshot) {
+        let current = self.inner.read();
+        // Destructured in full so a new snapshot field cannot be silently left stale.
+        let ObserverSnapshot {
+            state,
+            samples,
+            total_power,
+            flame_chase,
+            flips_last_tick,
+            dominant_path,
+            corruption_velocity,
+            collapse_momentum,
+            arena,
+            failed_spawns,
+            entropy_stats,
+        } = &*current;
+        buf.state = *state;
+        buf.samples.clone_from(samples);
+        buf.total_power = *total_power;
+        buf.flame_chase = *flame_chase;
+        buf.flips_last_tick = *flips_last_tick;
+        buf.dominant_path = *dominant_path;
+        buf.corruption_velocity = *corruption_velocity;
+        buf.collapse_momentum = *collapse_momentum;
+        buf.arena = *arena;
+        buf.failed_spawns = *failed_spawns;
+        buf.entropy_stats = *entropy_stats;
+    }
+
     fn update(&self, next: ObserverSnapshot) {
         *self.inner.write() = next;
     }
 }
 
+/// Cloneable handle for steering a running engine loop; see `ObserverRuntime::control`.
+///
+/// Settings are atomics the engine thread reads once per frame, so changes apply from the
+/// next frame and never wait on a lock.
+#[derive(Clone)]
+pub struct SharedObserverControl {
+    inner: Arc<ObserverControl>,
+}
+
+struct ObserverControl {
+    tick_hz: AtomicU64,
+    paused: AtomicBool,
+    engine_thread: OnceLock<Thread>,
+}
+
+impl SharedObserverControl {
+    fn new(tick_hz: u64) -> Self {
+        Self {
+            inner: Arc::new(ObserverControl {
+                tick_hz: AtomicU64::new(tick_hz.max(1)),
+                paused: AtomicBool::new(false),
+                engine_thread: OnceLock::new(),
+            }),
+        }
+    }
+
+    pub fn tick_hz(&self) -> u64 {
+        self.inner.tick_hz.load(Ordering::Relaxed)
+    }
+
+    /// Changes the fixed tick rate; 0 counts as 1.
+    pub fn set_tick_hz(&self, tick_hz: u64) {
+        self.inner.tick_hz.store(tick_hz.max(1), Ordering::Relaxed);
+        self.wake();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.inner.paused.load(Ordering::Relaxed)
+    }
+
+    /// Pausing parks the engine thread between ticks until resumed or shut down; time spent
+    /// paused is not caught up afterwards. Commands still queue while paused.
+    pub fn set_paused(&self, paused: bool) {
+        self.inner.paused.store(paused, Ordering::Relaxed);
+        self.wake();
+    }
+
+    fn attach(&self, engine_thread: &Thread) {
+        let _ = self.inner.engine_thread.set(engine_thread.clone());
+    }
+
+    fn wake(&self) {
+        if let Some(engine_thread) = self.inner.engine_thread.get() {
+            engine_thread.unpark();
+        }
+    }
+}
+
+/// Fixed timestep of a loop ticking `tick_hz` times per second.
+fn fixed_timestep(tick_hz: u64) -> Duration {
+    Duration::from_nanos((1_000_000_000_u64 / tick_hz.max(1)).max(1))
+}
+
+/// How the fixed-timestep loop reacts when more than one tick's worth of time has elapsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CatchUpStrategy {
+    /// Catch up on missed ticks, bounded to a few steps per frame after long stalls.
+    #[default]
+    Clamped,
+    /// Run exactly one tick per frame without accumulating elapsed time; the loop sleeps
+    /// out the rest of each `fixed_dt` frame instead.
+    SingleStep,
+    /// Catch up on every missed tick, however long the stall was.
+    Unbounded,
+}
+
+impl CatchUpStrategy {
+    const MAX_CLAMPED_STEPS: u32 = 8;
+
+    /// Returns how many ticks to run this frame, banking `frame_time` in `accumulator`
+    /// for the strategies that catch up.
+    fn plan_steps(
+        self,
+        accumulator: &mut Duration,
+        frame_time: Duration,
+        fixed_dt: Duration,
+    ) -> u32 {
+        match self {
+            Self::Clamped => {
+                // Clamp to prevent runaway catch-up after long stalls.
+                let clamped_frame =
+                    frame_time.min(fixed_dt.saturating_mul(Self::MAX_CLAMPED_STEPS));
+                *accumulator = accumulator.saturating_add(clamped_frame);
+                let steps = whole_steps(*accumulator, fixed_dt).min(Self::MAX_CLAMPED_STEPS);
+                *accumulator = accumulator.saturating_sub(fixed_dt.saturating_mul(steps));
+                steps
+            }
+            // Frame time is ignored entirely so slow and fast frames alike step once.
+            Self::SingleStep => 1,
+            Self::Unbounded => {
+                *accumulator = accumulator.saturating_add(frame_time);
+                let steps = whole_steps(*accumulator, fixed_dt);
+                *accumulator = accumulator.saturating_sub(fixed_dt.saturating_mul(steps));
+                steps
+            }
+        }
+    }
+}
+
+fn whole_steps(accumulator: Duration, fixed_dt: Duration) -> u32 {
+    let steps = accumulator.as_nanos() / fixed_dt.as_nanos().max(1);
+    u32::try_from(steps).unwrap_or(u32::MAX)
+}
+
+/// Loop parameters for `ObserverRuntime::spawn_with_config`.
+#[derive(Debug, Clone, Copy)]
+pub struct ObserverConfig {
+    pub tick_hz: u64,
+    pub max_samples: usize,
+    pub catch_up: CatchUpStrategy,
+    /// Ticks run before the first snapshot is exposed, so charts start populated.
+    ///
+    /// Warmup ticks are real ticks: they advance cycles and can trigger black tides.
+    pub warmup_ticks: u32,
+    /// Park the engine thread after this many consecutive idle ticks, until `poke` is called.
+    ///
+    /// A tick is idle when time was bypassed and entropy did not move. `None` never parks.
+    pub park_after_idle_ticks: Option<u32>,
+    /// Publish the shared snapshot once every this many ticks; samples are still kept per tick.
+    pub snapshot_every: u32,
+    /// Most recent collapses kept by `spawn_tide_recorder`; older records are dropped.
+    pub max_tide_records: usize,
+    /// Stop the engine thread after this many ticks, warmup included; 0 runs until dropped.
+    pub max_ticks: u64,
+    /// Upper bound on the bytes held by sample buffers; further limits `max_samples` when set.
+    ///
+    /// The engine thread's history and the published snapshot each hold a copy, so both
+    /// count against the budget. At least one sample is always kept.
+    pub history_byte_budget: Option<usize>,
+}
+
+impl ObserverConfig {
+    /// Number of samples the loop retains after applying the floor and the byte budget.
+    pub fn effective_max_samples(&self) -> usize {
+        let max_samples = self.max_samples.max(16);
+        match self.history_byte_budget {
+            Some(budget) => {
+                let per_sample = 2 * size_of::<EntropySample>();
+                max_samples.min(budget / per_sample).max(1)
+            }
+            None => max_samples,
+        }
+    }
+}
+
+impl Default for ObserverConfig {
+    fn default() -> Self {
+        Self {
+            tick_hz: 60,
+            max_samples: 360,
+            catch_up: CatchUpStrategy::default(),
+            warmup_ticks: 0,
+            park_after_idle_ticks: None,
+            snapshot_every: 1,
+            max_tide_records: 256,
+            max_ticks: 0,
+            history_byte_budget: None,
+        }
+    }
+}
+
+/// Bounded ring of annotated entropy samples kept by the engine thread.
+struct EntropyHistory {
+    samples: VecDeque<EntropySample>,
+    max_samples: usize,
+    last_sample_at: Option<Instant>,
+}
+
+impl EntropyHistory {
+    fn new(max_samples: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(max_samples),
+            max_samples,
+            last_sample_at: None,
+        }
+    }
+
+    /// Ticks `engine` once and records the resulting entropy sample.
+    fn tick(&mut self, engine: &mut AmphoreusEngine) -> SimulationResult {
+        let result = engine.tick();
+        let event = match result {
+            SimulationResult::BlackTideTriggered => Some(EntropyEvent::BlackTide),
+            SimulationResult::TickAdvanced | SimulationResult::TimeBypassed => None,
+        };
+
+        let now = Instant::now();
+        if let (Some(previous), Some(last_sample_at)) =
+            (self.samples.back_mut(), self.last_sample_at)
+        {
+            previous.held_secs = now.duration_since(last_sample_at).as_secs_f64();
+        }
+        self.last_sample_at = Some(now);
+
+        // Evict before pushing so the ring never grows past its initial allocation.
+        if self.samples.len() >= self.max_samples {
+            let _ = self.samples.pop_front();
+        }
+        self.samples.push_back(EntropySample {
+            entropy: engine.state.destruction_entropy,
+            event,
+            held_secs: 0.0,
+        });
+        result
+    }
+
+    fn to_vec(&self) -> Vec<EntropySample> {
+        self.samples.iter().copied().collect()
+    }
+
+    fn clear(&mut self) {
+        self.samples.clear();
+        self.last_sample_at = None;
+    }
+}
+
+/// Work handed to the engine thread, applied at the top of a frame before it ticks.
+pub enum EngineCommand {
+    /// Swaps in a new engine; see `ObserverRuntime::replace_engine`.
+    ReplaceEngine {
+        engine: Box<AmphoreusEngine>,
+        preserve_history: bool,
+    },
+    /// Wipes and reseeds the world; entropy history is kept.
+    Reseed(WorldSeedConfig),
+    /// Spawns one entity; failures are counted in `ObserverSnapshot::failed_spawns`.
+    Spawn(SpawnEntitySpec),
+    /// Switches the engine to `BlackTideTrigger::Threshold` at this entropy.
+    SetThreshold(f64),
+}
+
+/// Cloneable queue into a running engine thread, so every engine mutation stays on the
+/// thread that ticks it.
+#[derive(Clone)]
+pub struct CommandSender {
+    commands: Sender<EngineCommand>,
+    thread: Thread,
+}
+
+impl CommandSender {
+    /// Queues `command` and wakes a parked engine thread. Returns false when the engine
+    /// thread has already stopped.
+    pub fn send(&self, command: EngineCommand) -> bool {
+        let sent = self.commands.send(command).is_ok();
+        self.thread.unpark();
+        sent
+    }
+}
+
+/// Applies every queued command; returns whether any arrived.
+fn drain_commands(
+    commands: &Receiver<EngineCommand>,
+    engine: &mut AmphoreusEngine,
+    history: &mut EntropyHistory,
+) -> bool {
+    let mut applied = false;
+    while let Ok(command) = commands.try_recv() {
+        applied = true;
+        match command {
+            EngineCommand::ReplaceEngine {
+                engine: next,
+                preserve_history,
+            } => {
+                *engine = *next;
+                if !preserve_history {
+                    history.clear();
+                }
+            }
+            EngineCommand::Reseed(seed) => {
+                let _ = engine.seed_world(seed);
+            }
+            EngineCommand::Spawn(spec) => {
+                // Failures are counted by the engine and published as `failed_spawns`.
+                let _ = engine.spawn_entity(spec);
+            }
+            EngineCommand::SetThreshold(threshold) => {
+                let mut config = *engine.config();
+                config.black_tide_trigger = BlackTideTrigger::Threshold(threshold);
+                let _ = engine.set_config(config);
+            }
+        }
+    }
+    applied
+}
+
+/// Bounded list of collapse snapshots shared with a tide recorder's thread.
+struct TideLedger {
+    records: RwLock<Vec<ObserverSnapshot>>,
+    max_records: usize,
+}
+
+impl TideLedger {
+    fn record(&self, collapse: ObserverSnapshot) {
+        let mut records = self.records.write();
+        records.push(collapse);
+        if records.len() > self.max_records {
+            let excess = records.len() - self.max_records;
+            records.drain(..excess);
+        }
+    }
+}
+
+/// Synchronous per-tick hook for `ObserverRuntime::spawn_with_tick_callback`.
+pub type TickCallback = Box<dyn FnMut(&GlobalState, f64) + Send>;
+
+/// Body of an engine thread, handed to whichever spawner starts it.
+type EngineLoop = Box<dyn FnOnce() + Send>;
+
 pub struct ObserverRuntime {
     shared: SharedObserverSnapshot,
+    tide_ledger: Option<Arc<TideLedger>>,
     shutdown: Arc<AtomicBool>,
+    completed: Arc<AtomicBool>,
+    commands: CommandSender,
+    control: SharedObserverControl,
+    thread: Thread,
+    /// Joined on drop; `None` for scoped runtimes, whose scope joins the thread instead.
     handle: Option<JoinHandle<()>>,
 }
 
+/// An `ObserverRuntime` whose engine thread belongs to a `std::thread::scope`; see
+/// `ObserverRuntime::run_scoped`.
+pub struct ScopedObserverRuntime<'scope> {
+    runtime: ObserverRuntime,
+    handle: ScopedJoinHandle<'scope, ()>,
+}
+
+impl ScopedObserverRuntime<'_> {
+    /// Stops and joins the engine thread before the scope ends, returning the last snapshot
+    /// it published; see `ObserverRuntime::shutdown`.
+    pub fn shutdown(self) -> ObserverSnapshot {
+        let Self {
+            mut runtime,
+            handle,
+        } = self;
+        runtime.stop_and_join();
+        let _ = handle.join();
+        runtime.shared.read()
+    }
+}
+
+impl std::ops::Deref for ScopedObserverRuntime<'_> {
+    type Target = ObserverRuntime;
+
+    fn deref(&self) -> &ObserverRuntime {
+        &self.runtime
+    }
+}
+
 impl ObserverRuntime {
     /// Runs simulation with a fixed timestep loop on a dedicated thread.
-    pub fn spawn(mut engine: AmphoreusEngine, tick_hz: u64, max_samples: usize) -> Self {
-        let tick_hz = tick_hz.max(1);
-        let max_samples = max_samples.max(16);
-        let fixed_dt_nanos = (1_000_000_000_u64 / tick_hz).max(1);
-        let fixed_dt = Duration::from_nanos(fixed_dt_nanos);
-        let idle_sleep = Duration::from_millis(1);
-        let max_catch_up_steps = 8_u32;
+    ///
+    /// Returns the command queue with the runtime, so callers can mutate the engine on its
+    /// thread from the start; `command_sender` hands out more clones.
+    pub fn spawn(
+        engine: AmphoreusEngine,
+        tick_hz: u64,
+        max_samples: usize,
+    ) -> (Self, CommandSender) {
+        let runtime = Self::spawn_with_config(
+            engine,
+            ObserverConfig {
+                tick_hz,
+                max_samples,
+                ..ObserverConfig::default()
+            },
+        );
+        let commands = runtime.command_sender();
+        (runtime, commands)
+    }
 
-        let shared = SharedObserverSnapshot::new(ObserverSnapshot {
-            state: engine.state,
-            entropy_samples: Vec::with_capacity(max_samples),
+    /// Like `spawn`, with every loop parameter taken from `config`.
+    ///
+    /// Warmup ticks run on the calling thread before this returns.
+    pub fn spawn_with_config(engine: AmphoreusEngine, config: ObserverConfig) -> Self {
+        Self::spawn_detached(engine, config, None, None)
+    }
+
+    /// Runs the simulation at the default tick rate, recording a snapshot only when a black
+    /// tide fires.
+    pub fn spawn_tide_recorder(engine: AmphoreusEngine) -> Self {
+        Self::spawn_tide_recorder_with_config(engine, ObserverConfig::default())
+    }
+
+    /// Like `spawn_tide_recorder`, paced and bounded by `config`.
+    ///
+    /// Each collapse is captured on the tick that triggered it, after the world reseeds;
+    /// `state.destruction_entropy` still holds the entropy that broke the world. Recorded
+    /// snapshots carry no entropy samples, only the latest `max_tide_records` are kept, and
+    /// the shared snapshot shows the most recent one. `snapshot_every` does not apply.
+    pub fn spawn_tide_recorder_with_config(
+        engine: AmphoreusEngine,
+        config: ObserverConfig,
+    ) -> Self {
+        let ledger = TideLedger {
+            records: RwLock::default(),
+            max_records: config.max_tide_records.max(1),
+        };
+        Self::spawn_detached(engine, config, Some(Arc::new(ledger)), None)
+    }
+
+    /// Like `spawn_with_config`, calling `on_tick` on the engine thread after every stepped
+    /// tick with the new state and its destruction entropy.
+    ///
+    /// The callback runs between ticks and outside the snapshot lock, so readers never wait
+    /// on it, but the loop does: keep it short. Warmup ticks do not call it.
+    pub fn spawn_with_tick_callback(
+        engine: AmphoreusEngine,
+        config: ObserverConfig,
+        on_tick: TickCallback,
+    ) -> Self {
+        Self::spawn_detached(engine, config, None, Some(on_tick))
+    }
+
+    /// Runs a loop on a detached engine thread; dropping the runtime stops and joins it.
+    fn spawn_detached(
+        engine: AmphoreusEngine,
+        config: ObserverConfig,
+        tide_ledger: Option<Arc<TideLedger>>,
+        on_tick: Option<TickCallback>,
+    ) -> Self {
+        Self::spawn_loop(
+            engine,
+            config,
+            tide_ledger,
+            on_tick,
+            |builder, engine_loop| {
+                let handle = builder
+                    .spawn(engine_loop)
+                    .expect("failed to spawn amphoreus engine thread");
+                (handle.thread().clone(), Some(handle))
+            },
+        )
+    }
+
+    /// Like `spawn_with_config`, running the loop on a thread of `scope` instead of a
+    /// detached one.
+    ///
+    /// The returned runtime borrows the scope, so it cannot escape it; dropping it, at the
+    /// latest when the scope's closure returns, stops the loop, and the scope joins the
+    /// thread before it ends. Warmup ticks run on the calling thread before this returns.
+    pub fn run_scoped<'scope>(
+        scope: &'scope thread::Scope<'scope, '_>,
+        engine: AmphoreusEngine,
+        config: ObserverConfig,
+    ) -> ScopedObserverRuntime<'scope> {
+        let mut scoped_handle = None;
+        let runtime = Self::spawn_loop(engine, config, None, None, |builder, engine_loop| {
+            let handle = builder
+                .spawn_scoped(scope, engine_loop)
+                .expect("failed to spawn amphoreus engine thread");
+            let thread = handle.thread().clone();
+            scoped_handle = Some(handle);
+            (thread, None)
         });
+        ScopedObserverRuntime {
+            runtime,
+            handle: scoped_handle.expect("spawn_loop always spawns the engine thread"),
+        }
+    }
+
+    /// Prepares the fixed-timestep loop and starts it through `spawn`, which returns the
+    /// engine thread and, for detached threads, its join handle. With a `tide_ledger` the loop
+    /// publishes only collapses, and `on_tick` runs after every stepped tick.
+    fn spawn_loop(
+        mut engine: AmphoreusEngine,
+        config: ObserverConfig,
+        tide_ledger: Option<Arc<TideLedger>>,
+        mut on_tick: Option<TickCallback>,
+        spawn: impl FnOnce(thread::Builder, EngineLoop) -> (Thread, Option<JoinHandle<()>>),
+    ) -> Self {
+        let tick_hz = config.tick_hz.max(1);
+        let max_samples = config.effective_max_samples();
+        let catch_up = config.catch_up;
+        let park_after_idle_ticks = config.park_after_idle_ticks;
+        let snapshot_every = config.snapshot_every.max(1);
+        let max_ticks = config.max_ticks;
+        let tick_limit_reached = move |ticks_run: u64| max_ticks != 0 && ticks_run >= max_ticks;
+        let control = SharedObserverControl::new(tick_hz);
+        let control_for_thread = control.clone();
+        let idle_sleep = Duration::from_millis(1);
+
+        let mut entropy_history = EntropyHistory::new(max_samples);
+        let mut ticks_run = 0_u64;
+        for _ in 0..config.warmup_ticks {
+            if tick_limit_reached(ticks_run) {
+                break;
+            }
+            ticks_run += 1;
+            if entropy_history.tick(&mut engine) == SimulationResult::BlackTideTriggered
+                && let Some(ledger) = &tide_ledger
+            {
+                ledger.record(ObserverSnapshot::capture(&engine, Vec::new()));
+            }
+        }
+
+        let shared = SharedObserverSnapshot::new(ObserverSnapshot::capture(
+            &engine,
+            entropy_history.to_vec(),
+        ));
         let shared_for_thread = shared.clone();
+        let ledger_for_thread = tide_ledger.clone();
+        let publish_ticks = tide_ledger.is_none();
 
         let shutdown = Arc::new(AtomicBool::new(false));
         let shutdown_for_thread = Arc::clone(&shutdown);
+        let completed = Arc::new(AtomicBool::new(false));
+        let completed_for_thread = Arc::clone(&completed);
+        let (commands, command_queue) = mpsc::channel();
 
-        let handle = thread::Builder::new()
-            .name("amphoreus-engine-thread".to_owned())
-            .spawn(move || {
-                let mut entropy_history = VecDeque::with_capacity(max_samples);
+        let (thread, handle) = spawn(
+            thread::Builder::new().name("amphoreus-engine-thread".to_owned()),
+            Box::new(move || {
+                let mut tick_hz = control_for_thread.tick_hz();
+                let mut fixed_dt = fixed_timestep(tick_hz);
                 let mut previous_frame = Instant::now();
                 let mut accumulator = Duration::ZERO;
+                let mut idle_ticks = 0_u32;
+                let mut unpublished_ticks = 0_u32;
 
                 while !shutdown_for_thread.load(Ordering::Relaxed) {
+                    if drain_commands(&command_queue, &mut engine, &mut entropy_history) {
+                        idle_ticks = 0;
+                        if publish_ticks {
+                            unpublished_ticks = 0;
+                            shared_for_thread.update(ObserverSnapshot::capture(
+                                &engine,
+                                entropy_history.to_vec(),
+                            ));
+                        }
+                    }
+
+                    if tick_limit_reached(ticks_run) {
+                        if publish_ticks && unpublished_ticks > 0 {
+                            unpublished_ticks = 0;
+                            shared_for_thread.update(ObserverSnapshot::capture(
+                                &engine,
+                                entropy_history.to_vec(),
+                            ));
+                        }
+                        completed_for_thread.store(true, Ordering::Relaxed);
+                        shutdown_for_thread.store(true, Ordering::Relaxed);
+                        break;
+                    }
+
+                    if control_for_thread.is_paused()
+                        || park_after_idle_ticks.is_some_and(|limit| idle_ticks >= limit)
+                    {
+                        if publish_ticks && unpublished_ticks > 0 {
+                            unpublished_ticks = 0;
+                            shared_for_thread.update(ObserverSnapshot::capture(
+                                &engine,
+                                entropy_history.to_vec(),
+                            ));
+                        }
+                        thread::park();
+                        // Time spent parked is not owed to the simulation.
+                        idle_ticks = 0;
+                        accumulator = Duration::ZERO;
+                        previous_frame = Instant::now();
+                        continue;
+                    }
+
+                    if control_for_thread.tick_hz() != tick_hz {
+                        tick_hz = control_for_thread.tick_hz();
+                        fixed_dt = fixed_timestep(tick_hz);
+                    }
                     let now = Instant::now();
                     let frame_time = now.saturating_duration_since(previous_frame);
                     previous_frame = now;
 
-                    // Clamp to prevent runaway catch-up after long stalls.
-                    let clamped_frame = frame_time.min(fixed_dt.saturating_mul(max_catch_up_steps));
-                    accumulator = accumulator.saturating_add(clamped_frame);
-
-                    let mut steps = 0_u32;
-                    while accumulator >= fixed_dt && steps < max_catch_up_steps {
-                        let _ = engine.tick();
-                        accumulator = accumulator.saturating_sub(fixed_dt);
-                        steps += 1;
-
-                        entropy_history.push_back(engine.state.destruction_entropy);
-                        if entropy_history.len() > max_samples {
-                            let _ = entropy_history.pop_front();
+                    let mut steps = catch_up.plan_steps(&mut accumulator, frame_time, fixed_dt);
+                    if max_ticks != 0 {
+                        let remaining = max_ticks.saturating_sub(ticks_run);
+                        steps = steps.min(u32::try_from(remaining).unwrap_or(u32::MAX));
+                    }
+                    for _ in 0..steps {
+                        ticks_run += 1;
+                        let previous_entropy = engine.state.destruction_entropy;
+                        let result = entropy_history.tick(&mut engine);
+                        if let Some(on_tick) = on_tick.as_mut() {
+                            on_tick(&engine.state, engine.state.destruction_entropy);
+                        }
+                        if result == SimulationResult::BlackTideTriggered
+                            && let Some(ledger) = &ledger_for_thread
+                        {
+                            let collapse = ObserverSnapshot::capture(&engine, Vec::new());
+                            ledger.record(collapse.clone());
+                            shared_for_thread.update(collapse);
+                        }
+                        let idle = result == SimulationResult::TimeBypassed
+                            && engine.state.destruction_entropy == previous_entropy;
+                        idle_ticks = if idle {
+                            idle_ticks.saturating_add(1)
+                        } else {
+                            0
+                        };
+                        if park_after_idle_ticks.is_some_and(|limit| idle_ticks >= limit) {
+                            break;
                         }
                     }
 
                     if steps > 0 {
-                        shared_for_thread.update(ObserverSnapshot {
-                            state: engine.state,
-                            entropy_samples: entropy_history.iter().copied().collect(),
-                        });
+                        unpublished_ticks = unpublished_ticks.saturating_add(steps);
+                        if publish_ticks && unpublished_ticks >= snapshot_every {
+                            unpublished_ticks = 0;
+                            shared_for_thread.update(ObserverSnapshot::capture(
+                                &engine,
+                                entropy_history.to_vec(),
+                            ));
+                        }
+                        if catch_up == CatchUpStrategy::SingleStep {
+                            // Nothing is banked between frames, so pace them here instead.
+                            thread::sleep(fixed_dt.saturating_sub(now.elapsed()));
+                        }
                     } else {
                         thread::sleep(idle_sleep);
                     }
                 }
-            })
-            .expect("failed to spawn amphoreus engine thread");
+
+                // Ticks skipped by `snapshot_every` still reach whoever reads after a shutdown.
+                if publish_ticks && unpublished_ticks > 0 {
+                    shared_for_thread
+                        .update(ObserverSnapshot::capture(&engine, entropy_history.to_vec()));
+                }
+            }),
+        );
+        control.attach(&thread);
 
         Self {
             shared,
+            tide_ledger,
             shutdown,
-            handle: Some(handle),
+            completed,
+            commands: CommandSender {
+                commands,
+                thread: thread.clone(),
+            },
+            control,
+            thread,
+            handle,
         }
     }
 
     pub fn shared_snapshot(&self) -> SharedObserverSnapshot {
         self.shared.clone()
     }
-}
 
-impl Drop for ObserverRuntime {
-    fn drop(&mut self) {
+    /// Every collapse recorded by `spawn_tide_recorder`, oldest first; empty for other runtimes.
+    pub fn tide_history(&self) -> Vec<ObserverSnapshot> {
+        self.tide_ledger
+            .as_ref()
+            .map(|ledger| ledger.records.read().clone())
+            .unwrap_or_default()
+    }
+
+    /// Queue for mutating the engine on its own thread; clones outlive this borrow.
+    pub fn command_sender(&self) -> CommandSender {
+        self.commands.clone()
+    }
+
+    /// Handle for changing the tick rate or pausing the engine thread while it runs.
+    pub fn control(&self) -> SharedObserverControl {
+        self.control.clone()
+    }
+
+    /// Whether the engine thread stopped itself after `ObserverConfig::max_ticks` ticks.
+    pub fn is_completed(&self) -> bool {
+        self.completed.load(Ordering::Relaxed)
+    }
+
+    /// Swaps in `engine` on the engine thread before its next tick, e.g. after retuning.
+    ///
+    /// With `preserve_history` the entropy samples carry on from the old engine, so charts
+    /// stay continuous; otherwise they restart empty. Wakes a parked thread. Returns false
+    /// when the engine thread has already stopped.
+    pub fn replace_engine(&self, engine: AmphoreusEngine, preserve_history: bool) -> bool {
+        self.commands.send(EngineCommand::ReplaceEngine {
+            engine: Box::new(engine),
+            preserve_history,
+        })
+    }
+
+    /// Schedules `seed_world(seed)` on the engine thread, between ticks, so the wipe and
+    /// reseed never race the tick loop.
+    ///
+    /// Entropy history is kept. Wakes a parked thread. Returns false when the engine thread
+    /// has already stopped.
+    pub fn reseed(&self, seed: WorldSeedConfig) -> bool {
+        self.commands.send(EngineCommand::Reseed(seed))
+    }
+
+    /// Stops the engine thread, waits for it to finish, and returns the last snapshot it
+    /// published, which includes every tick it ran.
+    pub fn shutdown(mut self) -> ObserverSnapshot {
+        self.stop_and_join();
+        self.shared.read()
+    }
+
+    /// Signals the engine thread to stop and joins it if this runtime owns the join handle.
+    fn stop_and_join(&mut self) {
         self.shutdown.store(true, Ordering::Relaxed);
+        self.thread.unpark();
         if let Some(handle) = self.handle.take() {
             let _ = handle.join();
         }
     }
+
+    /// Wakes an engine thread parked by `ObserverConfig::park_after_idle_ticks`.
+    ///
+    /// Harmless when the thread is running.
+    pub fn poke(&self) {
+        self.thread.unpark();
+    }
+}
+
+impl Drop for ObserverRuntime {
+    fn drop(&mut self) {
+        self.stop_and_join();
+    }
 }
 
 #[cfg(all(feature = "desktop", not(target_arch = "wasm32")))]
@@ -137,7 +977,7 @@ pub fn read_global_state(state: tauri::State<'_, SharedObserverSnapshot>) -> Glo
 #[cfg(all(feature = "desktop", not(target_arch = "wasm32")))]
 #[tauri::command]
 pub fn read_entropy_series(state: tauri::State<'_, SharedObserverSnapshot>) -> Vec<f64> {
-    state.read().entropy_samples
+    state.read().entropy_samples()
 }
 
 #[cfg(all(feature = "desktop", not(target_arch = "wasm32")))]
@@ -161,7 +1001,6 @@ pub mod yew_frontend {
     use wasm_bindgen_futures::spawn_local;
     use yew::prelude::*;
 
-    use crate::engine::GlobalState;
     use crate::observer::ObserverSnapshot;
 
     #[wasm_bindgen(inline_js = r#"
@@ -177,16 +1016,11 @@ pub mod yew_frontend {
         async fn invoke_tauri(command: &str) -> Result<JsValue, JsValue>;
     }
 
-    async fn fetch_global_state() -> Result<GlobalState, JsValue> {
-        let value = invoke_tauri("read_global_state").await?;
+    /// Fetches state and samples in one round-trip, so they always come from the same tick.
+    async fn fetch_observer_snapshot() -> Result<ObserverSnapshot, JsValue> {
+        let value = invoke_tauri("read_observer_snapshot").await?;
         serde_wasm_bindgen::from_value(value)
-            .map_err(|err| Error::new(&format!("global_state decode failed: {err}")).into())
-    }
-
-    async fn fetch_entropy_series() -> Result<Vec<f64>, JsValue> {
-        let value = invoke_tauri("read_entropy_series").await?;
-        serde_wasm_bindgen::from_value(value)
-            .map_err(|err| Error::new(&format!("entropy decode failed: {err}")).into())
+            .map_err(|err| Error::new(&format!("observer snapshot decode failed: {err}")).into())
     }
 
     #[derive(Properties, PartialEq)]
@@ -199,10 +1033,7 @@ pub mod yew_frontend {
 
     #[function_component(Dashboard)]
     pub fn dashboard(props: &DashboardProps) -> Html {
-        let snapshot = use_state_eq(|| ObserverSnapshot {
-            state: GlobalState::default(),
-            entropy_samples: Vec::new(),
-        });
+        let snapshot = use_state_eq(ObserverSnapshot::default);
         let in_flight = use_mut_ref(|| false);
 
         {
@@ -227,19 +1058,8 @@ pub mod yew_frontend {
 
                         *in_flight.borrow_mut() = true;
 
-                        if let (Ok(state), Ok(mut entropy_samples)) =
-                            (fetch_global_state().await, fetch_entropy_series().await)
-                        {
-                            if entropy_samples.len() > max_points {
-                                let start = entropy_samples.len() - max_points;
-                                entropy_samples = entropy_samples[start..].to_vec();
-                            }
-
-                            let next = ObserverSnapshot {
-                                state,
-                                entropy_samples,
-                            };
-
+                        if let Ok(mut next) = fetch_observer_snapshot().await {
+                            next.retain_latest_samples(max_points);
                             if *snapshot != next {
                                 snapshot.set(next);
                             }
@@ -259,7 +1079,18 @@ pub mod yew_frontend {
                 <p>{ format!("Cycle Count: {}", snapshot.state.cycle_count) }</p>
                 <p>{ format!("Destruction Entropy: {:.6}", snapshot.state.destruction_entropy) }</p>
                 <p>{ format!("Time Concept Active: {}", snapshot.state.time_concept_active) }</p>
-                <EntropyChart samples={snapshot.entropy_samples.clone()} />
+                <p>{ format!("Collapse Momentum: {:.3}", snapshot.collapse_momentum) }</p>
+                <p>{ format!(
+                    "Arena: {} / {} bytes, peak {}",
+                    snapshot.arena.current, snapshot.arena.capacity, snapshot.arena.high_water
+                ) }</p>
+                if let Some(stats) = snapshot.entropy_stats {
+                    <p>{ format!(
+                        "Entropy min {:.4} / max {:.4} / mean {:.4} / stddev {:.4}",
+                        stats.min, stats.max, stats.mean, stats.stddev
+                    ) }</p>
+                }
+                <EntropyChart samples={snapshot.entropy_samples()} />
             </section>
         }
     }
@@ -305,3 +1136,763 @@ pub mod yew_frontend {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{Coreflame, EcsWorld, GoldenBlood, MemoryLog, Path};
+    use crate::engine::{BlackTideTrigger, EngineConfig, SpawnEntitySpec, WorldSeedConfig};
+    use crate::equation::EvalConfig;
+
+    /// A default-config engine simulating its own world, so tests never share entities.
+    fn independent_engine(arena_capacity: usize) -> AmphoreusEngine {
+        AmphoreusEngine::with_world(arena_capacity, EngineConfig::default(), EcsWorld::new(0))
+            .expect("default config is valid")
+    }
+
+    /// A reading with no event that has not been replaced yet.
+    fn reading(entropy: f64) -> EntropySample {
+        EntropySample {
+            entropy,
+            event: None,
+            held_secs: 0.0,
+        }
+    }
+
+    /// Polls the runtime's snapshot until `done` accepts it, failing after a few seconds.
+    fn wait_for(
+        runtime: &ObserverRuntime,
+        done: impl Fn(&ObserverSnapshot) -> bool,
+    ) -> ObserverSnapshot {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            let snapshot = runtime.shared_snapshot().read();
+            if done(&snapshot) {
+                return snapshot;
+            }
+            assert!(
+                Instant::now() < deadline,
+                "snapshot never reached the expected state"
+            );
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn tide_ticks_carry_the_black_tide_annotation() {
+        // Enough fully corrupted entities to push the first tick past 1.0.
+        let mut engine = independent_engine(1 << 25);
+        for _ in 0..750_000 {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    golden_blood: Some(GoldenBlood {
+                        corruption_level: 1.0,
+                    }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room");
+        }
+
+        let (runtime, _) = ObserverRuntime::spawn(engine, 1_000, 64);
+        let snapshot = wait_for(&runtime, |snapshot| snapshot.samples.len() >= 3);
+        drop(runtime);
+
+        let tide = snapshot.samples[0];
+        assert_eq!(tide.event, Some(EntropyEvent::BlackTide));
+        assert_eq!(tide.entropy, 1.0);
+        assert!(
+            snapshot.samples[1..]
+                .iter()
+                .all(|sample| sample.event.is_none() && sample.entropy < 1.0)
+        );
+        assert_eq!(snapshot.entropy_samples()[0], tide.entropy);
+    }
+
+    #[test]
+    fn single_step_runs_one_tick_per_frame_regardless_of_elapsed_time() {
+        let fixed_dt = Duration::from_millis(16);
+        let mut accumulator = Duration::from_millis(5);
+        for frame_time in [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            fixed_dt,
+            fixed_dt * 100,
+            Duration::from_secs(3_600),
+        ] {
+            assert_eq!(
+                CatchUpStrategy::SingleStep.plan_steps(&mut accumulator, frame_time, fixed_dt),
+                1
+            );
+            assert_eq!(accumulator, Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn clamped_and_unbounded_catch_up_differ_only_after_long_stalls() {
+        let fixed_dt = Duration::from_millis(10);
+        let plan = |strategy: CatchUpStrategy, frame_time| {
+            let mut accumulator = Duration::ZERO;
+            let steps = strategy.plan_steps(&mut accumulator, frame_time, fixed_dt);
+            (steps, accumulator)
+        };
+
+        let short = Duration::from_millis(25);
+        assert_eq!(
+            plan(CatchUpStrategy::Clamped, short),
+            (2, Duration::from_millis(5))
+        );
+        assert_eq!(
+            plan(CatchUpStrategy::Unbounded, short),
+            (2, Duration::from_millis(5))
+        );
+
+        let stall = Duration::from_secs(1);
+        assert_eq!(plan(CatchUpStrategy::Clamped, stall), (8, Duration::ZERO));
+        assert_eq!(
+            plan(CatchUpStrategy::Unbounded, stall),
+            (100, Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn entropy_histogram_bins_a_known_sample_set() {
+        let snapshot = ObserverSnapshot {
+            samples: [0.0, 0.1, 0.24, 0.25, 0.5, 0.74, 0.99, 1.0, -0.5, 1.5]
+                .into_iter()
+                .map(reading)
+                .collect(),
+            ..ObserverSnapshot::default()
+        };
+
+        assert_eq!(snapshot.entropy_histogram(4), [4, 1, 2, 3]);
+        assert_eq!(snapshot.entropy_histogram(1), [10]);
+        assert!(snapshot.entropy_histogram(0).is_empty());
+        assert_eq!(ObserverSnapshot::default().entropy_histogram(3), [0, 0, 0]);
+    }
+
+    #[test]
+    fn warmup_ticks_populate_the_first_exposed_snapshot() {
+        let config = ObserverConfig {
+            tick_hz: 1,
+            max_samples: 32,
+            warmup_ticks: 40,
+            ..ObserverConfig::default()
+        };
+        let runtime = ObserverRuntime::spawn_with_config(independent_engine(1 << 16), config);
+        let first = runtime.shared_snapshot().read();
+        drop(runtime);
+        assert_eq!(first.samples.len(), 32);
+        assert_eq!(first.state.cycle_count, 40);
+
+        // Warmup tides are real tides: they advance the cycle and annotate their samples.
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            EngineConfig {
+                black_tide_trigger: BlackTideTrigger::Threshold(0.0),
+                ..EngineConfig::default()
+            },
+            EcsWorld::new(0),
+        )
+        .expect("config is valid");
+        engine.world_seed = WorldSeedConfig {
+            citizens: 0,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        let runtime = ObserverRuntime::spawn_with_config(
+            engine,
+            ObserverConfig {
+                warmup_ticks: 3,
+                ..config
+            },
+        );
+        let first = runtime.shared_snapshot().read();
+        drop(runtime);
+        assert_eq!(first.state.cycle_count, 3);
+        assert_eq!(
+            first
+                .samples
+                .iter()
+                .map(|sample| sample.event)
+                .collect::<Vec<_>>(),
+            [Some(EntropyEvent::BlackTide); 3]
+        );
+    }
+
+    /// Polls until `done` accepts the snapshot, then checks it stays put for a while.
+    fn wait_until_settled(
+        runtime: &ObserverRuntime,
+        done: impl Fn(&ObserverSnapshot) -> bool,
+    ) -> ObserverSnapshot {
+        let settled = wait_for(runtime, done);
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(runtime.shared_snapshot().read(), settled);
+        settled
+    }
+
+    /// An engine whose every tick is idle: Cyrene alone keeps time bypassed, and rounding
+    /// pins her world's entropy at zero.
+    fn idle_cyrene_engine() -> AmphoreusEngine {
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            EngineConfig {
+                eval: EvalConfig {
+                    round_decimals: Some(3),
+                },
+                ..EngineConfig::default()
+            },
+            EcsWorld::new(0),
+        )
+        .expect("config is valid");
+        let cyrene = engine.spawn_entity(SpawnEntitySpec {
+            coreflame: Some(Coreflame {
+                power_level: 1.35,
+                alignment: Path::Remembrance,
+            }),
+            memory_log: Some(MemoryLog {
+                retained_cycles: 0,
+                trauma_index: 0.92,
+            }),
+            golden_blood: None,
+            cohort: None,
+            corruption_resistance: None,
+            faction: None,
+            lifespan: None,
+        });
+        engine.flame_chase.cyrene = cyrene
+            .ok()
+            .and_then(|cyrene| engine.world().read(|ecs| ecs.handle(cyrene)));
+        engine
+    }
+
+    #[test]
+    fn idle_engine_thread_parks_until_poked() {
+        let runtime = ObserverRuntime::spawn_with_config(
+            idle_cyrene_engine(),
+            ObserverConfig {
+                tick_hz: 1_000,
+                max_samples: 64,
+                park_after_idle_ticks: Some(5),
+                ..ObserverConfig::default()
+            },
+        );
+
+        let parked = wait_until_settled(&runtime, |snapshot| snapshot.samples.len() >= 5);
+        assert_eq!(parked.entropy_samples(), [0.0; 5]);
+        assert!(parked.samples.iter().all(|sample| sample.event.is_none()));
+        assert!(!parked.state.time_concept_active);
+
+        runtime.poke();
+        let reparked = wait_until_settled(&runtime, |snapshot| snapshot.samples.len() >= 10);
+        assert_eq!(reparked.samples.len(), 10);
+    }
+
+    #[test]
+    fn snapshot_every_publishes_every_fourth_tick_without_losing_samples() {
+        let runtime = ObserverRuntime::spawn_with_config(
+            idle_cyrene_engine(),
+            ObserverConfig {
+                tick_hz: 200,
+                max_samples: 64,
+                catch_up: CatchUpStrategy::SingleStep,
+                park_after_idle_ticks: Some(10),
+                snapshot_every: 4,
+                ..ObserverConfig::default()
+            },
+        );
+
+        // Exactly one tick per frame, so publications land on multiples of four; parking
+        // flushes the remainder.
+        let mut published = Vec::new();
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while published.last() != Some(&10) {
+            assert!(Instant::now() < deadline, "saw only {published:?}");
+            let len = runtime.shared_snapshot().read().samples.len();
+            if published.last() != Some(&len) {
+                published.push(len);
+            }
+            thread::sleep(Duration::from_micros(200));
+        }
+        assert!(published.iter().all(|len| [0, 4, 8, 10].contains(len)));
+
+        let parked = runtime.shared_snapshot().read();
+        assert_eq!(parked.state.cycle_count, 0);
+        assert_eq!(parked.entropy_samples(), [0.0; 10]);
+        assert!(parked.samples.iter().all(|sample| sample.event.is_none()));
+    }
+
+    #[test]
+    fn read_into_matches_read_and_reuses_the_sample_buffer() {
+        let snapshot = |entropy: f64, flips_last_tick: u32| ObserverSnapshot {
+            samples: (0..32)
+                .map(|idx| reading(entropy + f64::from(idx) * 1e-3))
+                .collect(),
+            total_power: entropy * 10.0,
+            flips_last_tick,
+            ..ObserverSnapshot::default()
+        };
+        let shared = SharedObserverSnapshot::new(snapshot(0.1, 3));
+
+        let mut buf = ObserverSnapshot::default();
+        shared.read_into(&mut buf);
+        assert_eq!(buf, shared.read());
+        let buffer = (buf.samples.as_ptr(), buf.samples.capacity());
+
+        shared.update(snapshot(0.4, 7));
+        shared.read_into(&mut buf);
+        assert_eq!(buf, shared.read());
+        assert_eq!((buf.samples.as_ptr(), buf.samples.capacity()), buffer);
+    }
+
+    #[test]
+    fn tide_recorder_keeps_one_bounded_entry_per_black_tide() {
+        // A zero threshold makes every tick a black tide, each advancing the cycle by one.
+        let mut engine = AmphoreusEngine::with_world(
+            1 << 16,
+            EngineConfig {
+                black_tide_trigger: BlackTideTrigger::Threshold(0.0),
+                ..EngineConfig::default()
+            },
+            EcsWorld::new(0),
+        )
+        .expect("config is valid");
+        engine.world_seed = WorldSeedConfig {
+            citizens: 0,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        };
+        let runtime = ObserverRuntime::spawn_tide_recorder_with_config(
+            engine,
+            ObserverConfig {
+                tick_hz: 200,
+                max_tide_records: 4,
+                ..ObserverConfig::default()
+            },
+        );
+
+        let early = wait_for(&runtime, |snapshot| snapshot.state.cycle_count >= 2);
+        let history = runtime.tide_history();
+        let cycles: Vec<u64> = history.iter().map(|tide| tide.state.cycle_count).collect();
+        assert_eq!(cycles[0], 1);
+        assert!(cycles.windows(2).all(|pair| pair[1] == pair[0] + 1));
+        assert!(history.iter().all(|tide| tide.samples.is_empty()));
+        assert!(early.state.cycle_count <= *cycles.last().expect("a tide was recorded"));
+
+        let _ = wait_for(&runtime, |snapshot| snapshot.state.cycle_count >= 10);
+        let latest = runtime.shared_snapshot().read();
+        let history = runtime.tide_history();
+        drop(runtime);
+
+        assert_eq!(history.len(), 4);
+        let cycles: Vec<u64> = history.iter().map(|tide| tide.state.cycle_count).collect();
+        assert!(cycles.windows(2).all(|pair| pair[1] == pair[0] + 1));
+        assert!(latest.state.cycle_count <= cycles[3]);
+    }
+
+    #[test]
+    fn tick_cap_stops_the_loop_at_exactly_max_ticks() {
+        for warmup_ticks in [0, 30] {
+            let runtime = ObserverRuntime::spawn_with_config(
+                independent_engine(1 << 16),
+                ObserverConfig {
+                    tick_hz: 1_000,
+                    warmup_ticks,
+                    snapshot_every: 7,
+                    max_ticks: 100,
+                    ..ObserverConfig::default()
+                },
+            );
+
+            let deadline = Instant::now() + Duration::from_secs(10);
+            while !runtime.is_completed() {
+                assert!(Instant::now() < deadline, "tick cap was never reached");
+                thread::sleep(Duration::from_millis(1));
+            }
+            let snapshot = runtime.shared_snapshot().read();
+            drop(runtime);
+            assert_eq!(snapshot.state.cycle_count, 100);
+            assert_eq!(snapshot.samples.len(), 100);
+        }
+    }
+
+    #[test]
+    fn snapshots_report_the_dominant_path() {
+        let mut engine = independent_engine(1 << 16);
+        assert_eq!(
+            ObserverSnapshot::capture(&engine, Vec::new()).dominant_path,
+            Path::None
+        );
+        for alignment in [Path::Erudition, Path::Destruction, Path::Destruction] {
+            engine
+                .spawn_entity(SpawnEntitySpec {
+                    coreflame: Some(Coreflame {
+                        power_level: 1.0,
+                        alignment,
+                    }),
+                    ..SpawnEntitySpec::default()
+                })
+                .expect("arena has room");
+        }
+        assert_eq!(
+            ObserverSnapshot::capture(&engine, Vec::new()).dominant_path,
+            Path::Destruction
+        );
+    }
+
+    #[test]
+    fn decoded_snapshot_keeps_state_and_its_newest_samples_together() {
+        let engine = idle_cyrene_engine();
+        let samples = (0..10).map(|i| reading(f64::from(i) / 10.0)).collect();
+        let sent = ObserverSnapshot::capture(&engine, samples);
+
+        let bytes = bincode::serde::encode_to_vec(&sent, bincode::config::standard())
+            .expect("snapshot encodes");
+        let (mut received, _): (ObserverSnapshot, _) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::standard())
+                .expect("snapshot decodes");
+        assert_eq!(received, sent);
+
+        received.retain_latest_samples(4);
+        assert_eq!(received.samples, sent.samples[6..]);
+        assert_eq!(received.state, sent.state);
+        assert_eq!(received.flame_chase, sent.flame_chase);
+        received.retain_latest_samples(8);
+        assert_eq!(received.samples.len(), 4);
+    }
+
+    #[test]
+    fn history_byte_budget_bounds_both_sample_buffers() {
+        let per_sample = 2 * size_of::<EntropySample>();
+        let budgeted = |history_byte_budget| ObserverConfig {
+            history_byte_budget,
+            ..ObserverConfig::default()
+        };
+        assert_eq!(budgeted(None).effective_max_samples(), 360);
+        assert_eq!(
+            budgeted(Some(12 * per_sample + 1)).effective_max_samples(),
+            12
+        );
+        assert_eq!(budgeted(Some(1 << 30)).effective_max_samples(), 360);
+        assert_eq!(budgeted(Some(0)).effective_max_samples(), 1);
+
+        let mut history = EntropyHistory::new(12);
+        let capacity = history.samples.capacity();
+        let mut engine = independent_engine(1 << 16);
+        for _ in 0..50 {
+            history.tick(&mut engine);
+        }
+        assert_eq!(history.samples.len(), 12);
+        assert_eq!(history.samples.capacity(), capacity);
+
+        let runtime = ObserverRuntime::spawn_with_config(
+            independent_engine(1 << 16),
+            ObserverConfig {
+                tick_hz: 1_000,
+                max_ticks: 50,
+                ..budgeted(Some(12 * per_sample))
+            },
+        );
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !runtime.is_completed() {
+            assert!(Instant::now() < deadline, "tick cap was never reached");
+            thread::sleep(Duration::from_millis(1));
+        }
+        let snapshot = runtime.shared_snapshot().read();
+        drop(runtime);
+        assert_eq!(snapshot.samples.len(), 12);
+    }
+
+    #[test]
+    fn time_weighted_mean_weights_each_reading_by_how_long_it_held() {
+        let held = |entropy, held_secs| EntropySample {
+            entropy,
+            event: None,
+            held_secs,
+        };
+        // 0.2 for 3s, 0.8 for 1s, 0.5 for 0s (still holding): (0.6 + 0.8) / 4.
+        let uneven = ObserverSnapshot {
+            samples: vec![held(0.2, 3.0), held(0.8, 1.0), held(0.5, 0.0)],
+            ..ObserverSnapshot::default()
+        };
+        let mean = uneven.time_weighted_mean_entropy().expect("has samples");
+        assert!((mean - 0.35).abs() < 1e-12, "{mean}");
+
+        let untimed = ObserverSnapshot {
+            samples: vec![reading(0.2), reading(0.8)],
+            ..ObserverSnapshot::default()
+        };
+        assert_eq!(untimed.time_weighted_mean_entropy(), Some(0.5));
+        assert_eq!(
+            ObserverSnapshot::default().time_weighted_mean_entropy(),
+            None
+        );
+
+        let mut history = EntropyHistory::new(8);
+        let mut engine = independent_engine(1 << 16);
+        for pause_ms in [0, 20, 5] {
+            thread::sleep(Duration::from_millis(pause_ms));
+            history.tick(&mut engine);
+        }
+        let held_secs: Vec<f64> = history.samples.iter().map(|s| s.held_secs).collect();
+        assert!(
+            held_secs[0] >= 0.02 && held_secs[1] >= 0.005,
+            "{held_secs:?}"
+        );
+        assert_eq!(held_secs[2], 0.0);
+    }
+
+    #[test]
+    fn replace_engine_keeps_or_restarts_the_entropy_history() {
+        for preserve_history in [true, false] {
+            let runtime = ObserverRuntime::spawn_with_config(
+                idle_cyrene_engine(),
+                ObserverConfig {
+                    tick_hz: 1_000,
+                    max_samples: 64,
+                    park_after_idle_ticks: Some(5),
+                    ..ObserverConfig::default()
+                },
+            );
+            let parked = wait_until_settled(&runtime, |snapshot| snapshot.samples.len() >= 5);
+            assert_eq!(parked.state.cycle_count, 0);
+
+            let mut retuned = idle_cyrene_engine();
+            retuned.state.cycle_count = 42;
+            assert!(runtime.replace_engine(retuned, preserve_history));
+            let expected = if preserve_history { 10 } else { 5 };
+            let reparked = wait_until_settled(&runtime, |snapshot| {
+                snapshot.state.cycle_count == 42 && snapshot.samples.len() >= expected
+            });
+            assert_eq!(reparked.samples.len(), expected, "{preserve_history}");
+            if preserve_history {
+                // The newest parked sample gains its hold time once the next one lands.
+                assert_eq!(reparked.samples[..4], parked.samples[..4]);
+                assert_eq!(reparked.samples[4].entropy, parked.samples[4].entropy);
+            }
+        }
+    }
+
+    #[test]
+    fn scheduled_reseed_builds_a_fresh_world_on_the_engine_thread() {
+        let engine = idle_cyrene_engine();
+        let world = engine.world().clone();
+        let runtime = ObserverRuntime::spawn_with_config(
+            engine,
+            ObserverConfig {
+                tick_hz: 1_000,
+                max_samples: 64,
+                ..ObserverConfig::default()
+            },
+        );
+        let before = wait_for(&runtime, |snapshot| snapshot.samples.len() >= 3);
+        assert_eq!(before.flame_chase.phainon_trauma, None);
+
+        assert!(runtime.reseed(WorldSeedConfig {
+            citizens: 7,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        }));
+        let reseeded = wait_for(&runtime, |snapshot| {
+            snapshot.flame_chase.phainon_trauma.is_some()
+        });
+        assert!(reseeded.samples.len() >= before.samples.len());
+        drop(runtime);
+        // Seven citizens plus the flame-chase pair, even if a black tide has since reseeded.
+        assert_eq!(world.read(|ecs| ecs.entity_count()), 9);
+    }
+
+    #[test]
+    fn tick_callback_fires_once_per_stepped_tick_with_that_ticks_state() {
+        let calls = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let calls_for_callback = Arc::clone(&calls);
+        let runtime = ObserverRuntime::spawn_with_tick_callback(
+            independent_engine(1 << 16),
+            ObserverConfig {
+                tick_hz: 1_000,
+                warmup_ticks: 5,
+                max_ticks: 60,
+                ..ObserverConfig::default()
+            },
+            Box::new(move |state: &GlobalState, entropy| {
+                calls_for_callback.lock().push((*state, entropy));
+            }),
+        );
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !runtime.is_completed() {
+            assert!(Instant::now() < deadline, "tick cap was never reached");
+            thread::sleep(Duration::from_millis(1));
+        }
+        let snapshot = runtime.shared_snapshot().read();
+        drop(runtime);
+
+        let calls = calls.lock();
+        assert_eq!(calls.len(), 55);
+        for (i, (state, entropy)) in calls.iter().enumerate() {
+            assert_eq!(state.cycle_count, 6 + i as u64);
+            assert_eq!(*entropy, state.destruction_entropy);
+        }
+        assert_eq!(calls.last().map(|(state, _)| *state), Some(snapshot.state));
+        let sampled: Vec<f64> = snapshot
+            .samples
+            .iter()
+            .map(|sample| sample.entropy)
+            .collect();
+        let reported: Vec<f64> = calls.iter().map(|(_, entropy)| *entropy).collect();
+        assert_eq!(sampled[5..], reported[..]);
+    }
+
+    #[test]
+    fn scoped_runtime_stops_when_its_scope_ends() {
+        let config = ObserverConfig {
+            tick_hz: 1_000,
+            ..ObserverConfig::default()
+        };
+        let shared = thread::scope(|scope| {
+            let runtime = ObserverRuntime::run_scoped(scope, independent_engine(1 << 16), config);
+            wait_for(&runtime, |snapshot| snapshot.samples.len() >= 3);
+            runtime.shared_snapshot()
+        });
+
+        // The scope joined the engine thread, so nothing publishes any more.
+        let stopped = shared.read();
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(shared.read(), stopped);
+        assert!(stopped.state.cycle_count >= 3);
+    }
+
+    #[test]
+    fn entropy_stats_summarize_a_known_sample_set() {
+        assert_eq!(EntropyStats::from_samples(&[]), None);
+        let samples: Vec<EntropySample> = [0.2, 0.4, 0.4, 0.4, 0.5, 0.5, 0.7, 0.9]
+            .into_iter()
+            .map(reading)
+            .collect();
+        let stats = EntropyStats::from_samples(&samples).expect("has samples");
+        assert_eq!((stats.min, stats.max, stats.last), (0.2, 0.9, 0.9));
+        assert!((stats.mean - 0.5).abs() < 1e-12, "{}", stats.mean);
+        // Squared deviations sum to 0.32 over eight samples.
+        assert!((stats.stddev - 0.2).abs() < 1e-12, "{}", stats.stddev);
+    }
+
+    #[test]
+    fn published_entropy_stats_always_describe_the_published_samples() {
+        let (runtime, _) = ObserverRuntime::spawn(idle_cyrene_engine(), 1_000, 16);
+        assert_eq!(ObserverSnapshot::default().entropy_stats, None);
+
+        let mut buf = ObserverSnapshot::default();
+        for _ in 0..20 {
+            let snapshot = runtime.shared_snapshot().read();
+            assert_eq!(
+                snapshot.entropy_stats,
+                EntropyStats::from_samples(&snapshot.samples)
+            );
+            runtime.shared_snapshot().read_into(&mut buf);
+            assert_eq!(buf.entropy_stats, EntropyStats::from_samples(&buf.samples));
+            thread::sleep(Duration::from_millis(1));
+        }
+        let filled = wait_for(&runtime, |snapshot| snapshot.samples.len() >= 16);
+        drop(runtime);
+        assert!(filled.entropy_stats.is_some());
+    }
+
+    #[test]
+    fn shutdown_returns_a_snapshot_holding_every_tick_run() {
+        let config = ObserverConfig {
+            tick_hz: 1_000,
+            max_samples: 4_096,
+            snapshot_every: u32::MAX,
+            ..ObserverConfig::default()
+        };
+        let runtime = ObserverRuntime::spawn_with_config(independent_engine(1 << 16), config);
+        let shared = runtime.shared_snapshot();
+        thread::sleep(Duration::from_millis(30));
+        // `snapshot_every` holds every tick back, so only the initial snapshot is out.
+        assert!(shared.read().samples.is_empty());
+
+        // Dropping the consumed runtime after the join must not join again.
+        let last = runtime.shutdown();
+        assert!(last.state.cycle_count > 0);
+        assert_eq!(last.samples.len() as u64, last.state.cycle_count);
+        assert_eq!(shared.read(), last);
+
+        let last = thread::scope(|scope| {
+            ObserverRuntime::run_scoped(scope, independent_engine(1 << 16), config).shutdown()
+        });
+        assert_eq!(last.samples.len() as u64, last.state.cycle_count);
+    }
+
+    #[test]
+    fn halving_the_tick_rate_halves_cycle_growth_and_pausing_stops_it() {
+        let (runtime, _) = ObserverRuntime::spawn(independent_engine(1 << 16), 200, 16);
+        let shared = runtime.shared_snapshot();
+        let control = runtime.control();
+        let cycles_over = |window: Duration| {
+            let start = shared.read().state.cycle_count;
+            thread::sleep(window);
+            shared.read().state.cycle_count - start
+        };
+
+        let window = Duration::from_millis(500);
+        let full_rate = cycles_over(window);
+        control.set_tick_hz(100);
+        assert_eq!(control.tick_hz(), 100);
+        // Let the first frame at the new rate land before measuring.
+        thread::sleep(Duration::from_millis(20));
+        let half_rate = cycles_over(window);
+        let ratio = half_rate as f64 / full_rate as f64;
+        assert!(
+            (0.35..=0.65).contains(&ratio),
+            "{full_rate} then {half_rate}"
+        );
+
+        control.set_paused(true);
+        assert!(control.is_paused());
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(cycles_over(Duration::from_millis(100)), 0);
+        control.set_paused(false);
+        wait_for(&runtime, |snapshot| {
+            snapshot.state.cycle_count > full_rate + half_rate + 5
+        });
+
+        // A paused thread still hears the shutdown.
+        control.set_paused(true);
+        let last = runtime.shutdown();
+        assert!(last.state.cycle_count > 0);
+    }
+
+    #[test]
+    fn reseed_command_from_spawn_restarts_the_cycle_behavior() {
+        let (runtime, commands) = ObserverRuntime::spawn(independent_engine(1 << 16), 1_000, 64);
+        let shared = runtime.shared_snapshot();
+        let running = wait_for(&runtime, |snapshot| snapshot.state.cycle_count >= 3);
+        assert!(running.state.time_concept_active);
+
+        // A seeded world brings Cyrene's time exploit, which stops the cycle count.
+        assert!(commands.send(EngineCommand::Reseed(WorldSeedConfig {
+            citizens: 7,
+            titans: 0,
+            chrysos_heirs: 0,
+            ..WorldSeedConfig::default()
+        })));
+        let reseeded = wait_for(&runtime, |snapshot| !snapshot.state.time_concept_active);
+        assert!(reseeded.flame_chase.phainon_trauma.is_some());
+        let frozen = shared.read().state.cycle_count;
+        thread::sleep(Duration::from_millis(30));
+        assert_eq!(shared.read().state.cycle_count, frozen);
+        assert!(shared.read().samples.len() > reseeded.samples.len());
+    }
+
+    #[test]
+    fn failed_spawn_commands_are_counted_in_the_snapshot() {
+        let (runtime, commands) = ObserverRuntime::spawn(independent_engine(0), 60, 16);
+        runtime.control().set_paused(true);
+        assert!(commands.send(EngineCommand::Spawn(SpawnEntitySpec::default())));
+        assert!(commands.send(EngineCommand::Spawn(SpawnEntitySpec::default())));
+        wait_for(&runtime, |snapshot| snapshot.failed_spawns == 2);
+        assert_eq!(runtime.shutdown().failed_spawns, 2);
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use bincode::config::standard;
+use bincode::error::DecodeError;
+use bincode::serde::decode_from_slice;
+use serde::{Deserialize, Serialize};
+
+use crate::ecs::SoaEcs;
+use crate::engine::{
+    BlackTideRecord, ConfigError, EngineConfig, FlameChaseHandles, GlobalState, PersistentMemory,
+    StateCheckpoint, WorldSeedConfig,
+};
+use crate::rng::SimRng;
+
+/// Leading bytes of every eternal page, followed by a one-byte codec tag.
+const PAGE_MAGIC: &[u8; 4] = b"AMPH";
+/// Leading bytes of a state-only checkpoint, framed exactly like a page.
+const STATE_MAGIC: &[u8; 4] = b"AMST";
+const CODEC_NONE: u8 = 0;
+const CODEC_ZSTD: u8 = 1;
+/// Set on the codec tag when an FNV-1a checksum of the body follows it, little-endian.
+const CHECKSUM_FLAG: u8 = 0x80;
+/// Set on the codec tag when a little-endian `u16` layout version follows it.
+const VERSION_FLAG: u8 = 0x40;
+/// Layout version written by this build; pages without a version tag count as version 1.
+pub const PAGE_VERSION: u16 = 12;
+
+/// How the payload of an eternal page is compressed on disk.
+///
+/// The codec is recorded in the page header, so reading never needs to be told which one
+/// was used. `Zstd` requires the `zstd` feature; without it pages are written uncompressed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PageCompression {
+    #[default]
+    None,
+    Zstd(i32),
+}
+
+/// On-disk layout of an eternal page.
+///
+/// `M` is a borrowed byte slice and `W` a borrowed world when writing; both are owned when
+/// reading back.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct EternalPage<M, W> {
+    pub state: GlobalState,
+    pub config: EngineConfig,
+    pub world_seed: WorldSeedConfig,
+    pub arena_capacity: usize,
+    pub arena_offset: usize,
+    pub arena_memory: M,
+    pub black_tides: Vec<BlackTideRecord>,
+    pub rng: SimRng,
+    pub flame_chase: FlameChaseHandles,
+    /// Handles of entities that were dead at save time are dropped.
+    pub persistent_memories: Vec<PersistentMemory>,
+    /// The engine's whole world, including ID allocation state and every component store;
+    /// `None` when the page carries no world, which restores as an empty one.
+    pub ecs: Option<W>,
+}
+
+/// Why an eternal page could not be turned back into an engine.
+#[derive(Debug)]
+pub enum RestoreError {
+    Io(io::Error),
+    Decode(DecodeError),
+    /// The file does not start with the magic of the expected file kind.
+    NotAPage,
+    /// The page uses a codec this build cannot decompress.
+    UnsupportedCodec(u8),
+    /// The page was written with a layout version this build cannot read.
+    UnsupportedVersion(u16),
+    /// The page body does not match its recorded checksum.
+    ChecksumMismatch,
+    /// The stored engine config no longer passes validation.
+    InvalidConfig(ConfigError),
+}
+
+impl fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read eternal page: {err}"),
+            Self::Decode(err) => write!(f, "failed to decode eternal page: {err}"),
+            Self::NotAPage => write!(f, "file is not an eternal page"),
+            Self::UnsupportedCodec(codec) => {
+                write!(
+                    f,
+                    "eternal page codec {codec} is not supported by this build"
+                )
+            }
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "eternal page layout version {version} is not supported, expected {PAGE_VERSION}"
+            ),
+            Self::ChecksumMismatch => write!(f, "eternal page failed its checksum"),
+            Self::InvalidConfig(err) => write!(f, "eternal page holds an invalid config: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Decode(err) => Some(err),
+            Self::InvalidConfig(err) => Some(err),
+            Self::NotAPage
+            | Self::UnsupportedCodec(_)
+            | Self::UnsupportedVersion(_)
+            | Self::ChecksumMismatch => None,
+        }
+    }
+}
+
+impl From<io::Error> for RestoreError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ConfigError> for RestoreError {
+    fn from(err: ConfigError) -> Self {
+        Self::InvalidConfig(err)
+    }
+}
+
+impl From<DecodeError> for RestoreError {
+    fn from(err: DecodeError) -> Self {
+        Self::Decode(err)
+    }
+}
+
+/// Prefixes the serialized `payload` with the page header, compressing it as requested.
+pub(crate) fn encode_page(payload: &[u8], compression: PageCompression) -> io::Result<Vec<u8>> {
+    encode_framed(PAGE_MAGIC, payload, compression)
+}
+
+/// Frames a serialized `StateCheckpoint` for `AmphoreusEngine::save_state_only`.
+pub(crate) fn encode_state(payload: &[u8]) -> io::Result<Vec<u8>> {
+    encode_framed(STATE_MAGIC, payload, PageCompression::None)
+}
+
+fn encode_framed(
+    magic: &[u8; 4],
+    payload: &[u8],
+    compression: PageCompression,
+) -> io::Result<Vec<u8>> {
+    let (codec, body) = match compression {
+        PageCompression::None => (CODEC_NONE, payload.to_vec()),
+        #[cfg(all(feature = "zstd", not(target_arch = "wasm32")))]
+        PageCompression::Zstd(level) => (CODEC_ZSTD, zstd::encode_all(payload, level)?),
+        #[cfg(not(all(feature = "zstd", not(target_arch = "wasm32"))))]
+        PageCompression::Zstd(_) => (CODEC_NONE, payload.to_vec()),
+    };
+
+    let mut bytes = Vec::with_capacity(magic.len() + 1 + 2 + 8 + body.len());
+    bytes.extend_from_slice(magic);
+    bytes.push(codec | VERSION_FLAG | CHECKSUM_FLAG);
+    bytes.extend_from_slice(&PAGE_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&checksum(&body).to_le_bytes());
+    bytes.extend_from_slice(&body);
+    Ok(bytes)
+}
+
+/// 64-bit FNV-1a, enough to catch truncated or bit-flipped pages.
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+/// Strips the header behind `magic` and decompresses the payload according to its codec tag.
+fn decode_payload(magic: &[u8; 4], bytes: &[u8]) -> Result<Vec<u8>, RestoreError> {
+    let body = bytes.strip_prefix(magic).ok_or(RestoreError::NotAPage)?;
+    let (&tag, mut body) = body.split_first().ok_or(RestoreError::NotAPage)?;
+
+    let version = if tag & VERSION_FLAG != 0 {
+        let (version, rest) = body
+            .split_first_chunk::<2>()
+            .ok_or(RestoreError::NotAPage)?;
+        body = rest;
+        u16::from_le_bytes(*version)
+    } else {
+        1
+    };
+    if version != PAGE_VERSION {
+        return Err(RestoreError::UnsupportedVersion(version));
+    }
+
+    if tag & CHECKSUM_FLAG != 0 {
+        let (expected, rest) = body
+            .split_first_chunk::<8>()
+            .ok_or(RestoreError::ChecksumMismatch)?;
+        if checksum(rest) != u64::from_le_bytes(*expected) {
+            return Err(RestoreError::ChecksumMismatch);
+        }
+        body = rest;
+    }
+
+    let codec = tag & !(CHECKSUM_FLAG | VERSION_FLAG);
+    match codec {
+        CODEC_NONE => Ok(body.to_vec()),
+        #[cfg(all(feature = "zstd", not(target_arch = "wasm32")))]
+        CODEC_ZSTD => Ok(zstd::decode_all(body)?),
+        #[cfg(not(all(feature = "zstd", not(target_arch = "wasm32"))))]
+        CODEC_ZSTD => Err(RestoreError::UnsupportedCodec(codec)),
+        other => Err(RestoreError::UnsupportedCodec(other)),
+    }
+}
+
+pub(crate) fn read_page(
+    file_path: impl AsRef<Path>,
+) -> Result<EternalPage<Vec<u8>, SoaEcs>, RestoreError> {
+    let bytes = fs::read(file_path)?;
+    let payload = decode_payload(PAGE_MAGIC, &bytes)?;
+    let (page, _) = decode_from_slice(&payload, standard())?;
+    Ok(page)
+}
+
+pub(crate) fn read_state(file_path: &str) -> Result<StateCheckpoint, RestoreError> {
+    let bytes = fs::read(file_path)?;
+    let payload = decode_payload(STATE_MAGIC, &bytes)?;
+    let (checkpoint, _) = decode_from_slice(&payload, standard())?;
+    Ok(checkpoint)
+}